@@ -7,6 +7,14 @@ pub enum SqlValue {
     Float(f64),
     Text(String),
     Boolean(bool),
+    /// A calendar date formatted as `YYYY-MM-DD`
+    Date(String),
+    /// A date and time formatted as `YYYY-MM-DD HH:MM:SS`
+    Timestamp(String),
+    /// A UUID in its hyphenated form
+    Uuid(String),
+    /// A JSON document in its compact text form
+    Json(String),
     Null,
 }
 
@@ -31,6 +39,26 @@ impl SqlValue {
         SqlValue::Boolean(value)
     }
 
+    /// Creates a new Date variant from a `YYYY-MM-DD` string
+    pub fn date<T: Into<String>>(value: T) -> Self {
+        SqlValue::Date(value.into())
+    }
+
+    /// Creates a new Timestamp variant from a `YYYY-MM-DD HH:MM:SS` string
+    pub fn timestamp<T: Into<String>>(value: T) -> Self {
+        SqlValue::Timestamp(value.into())
+    }
+
+    /// Creates a new Uuid variant from its hyphenated form
+    pub fn uuid<T: Into<String>>(value: T) -> Self {
+        SqlValue::Uuid(value.into())
+    }
+
+    /// Creates a new Json variant from the text of a JSON document
+    pub fn json<T: Into<String>>(value: T) -> Self {
+        SqlValue::Json(value.into())
+    }
+
     /// Creates a Null variant
     pub fn null() -> Self {
         SqlValue::Null
@@ -48,6 +76,10 @@ impl SqlValue {
             SqlValue::Float(_) => "REAL",
             SqlValue::Text(_) => "TEXT",
             SqlValue::Boolean(_) => "BOOLEAN",
+            SqlValue::Date(_) => "DATE",
+            SqlValue::Timestamp(_) => "TIMESTAMP",
+            SqlValue::Uuid(_) => "UUID",
+            SqlValue::Json(_) => "JSON",
             SqlValue::Null => "NULL",
         }
     }
@@ -60,6 +92,10 @@ impl Display for SqlValue {
             SqlValue::Float(fl) => write!(f, "{}", fl),
             SqlValue::Text(s) => write!(f, "{}", s),
             SqlValue::Boolean(b) => write!(f, "{}", b),
+            SqlValue::Date(d) => write!(f, "{}", d),
+            SqlValue::Timestamp(t) => write!(f, "{}", t),
+            SqlValue::Uuid(u) => write!(f, "{}", u),
+            SqlValue::Json(j) => write!(f, "{}", j),
             SqlValue::Null => write!(f, "NULL"),
         }
     }
@@ -106,3 +142,124 @@ where
         }
     }
 }
+
+/// Step of a seeder, the drivers run the steps in order inside one
+/// transaction
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// A row inserted into a table
+    Insert {
+        table: String,
+        row: Vec<(String, SqlValue)>,
+    },
+    /// Raw SQL, such as a statement of a `.sql` seeder
+    Sql(String),
+}
+
+impl Statement {
+    /// Statements inserting the rows of each table, table by table
+    pub fn inserts(
+        tables: impl IntoIterator<Item = (String, Vec<Vec<(String, SqlValue)>>)>,
+    ) -> Vec<Statement> {
+        tables
+            .into_iter()
+            .flat_map(|(table, rows)| {
+                rows.into_iter().map(move |row| Statement::Insert {
+                    table: table.clone(),
+                    row,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Statements grouped to run as one query, consecutive inserts with the same
+/// table and columns become a multi-row insert
+#[derive(Debug, Clone, PartialEq)]
+pub enum Batch {
+    Insert {
+        table: String,
+        columns: Vec<String>,
+        rows: Vec<Vec<SqlValue>>,
+    },
+    Sql(String),
+}
+
+impl Statement {
+    /// Groups the statements in batches of up to `size` rows, keeping their
+    /// order. A size of 0 or 1 inserts each row on its own.
+    pub fn batches(statements: Vec<Statement>, size: usize) -> Vec<Batch> {
+        let mut batcher = Batcher::new(size);
+        let mut batches: Vec<Batch> = statements
+            .into_iter()
+            .flat_map(|statement| batcher.push(statement))
+            .collect();
+        batches.extend(batcher.finish());
+
+        batches
+    }
+}
+
+/// Groups statements in batches as they come, as [`Statement::batches`], so
+/// a driver runs each batch once it is full instead of after the last
+/// statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct Batcher {
+    size: usize,
+    pending: Option<Batch>,
+}
+
+impl Batcher {
+    pub fn new(size: usize) -> Self {
+        Batcher {
+            size: size.max(1),
+            pending: None,
+        }
+    }
+
+    /// Adds a statement and returns the batches ready to run, in order
+    pub fn push(&mut self, statement: Statement) -> Vec<Batch> {
+        let mut ready = Vec::new();
+
+        let (table, row) = match statement {
+            Statement::Insert { table, row } => (table, row),
+            Statement::Sql(sql) => {
+                ready.extend(self.pending.take());
+                ready.push(Batch::Sql(sql));
+                return ready;
+            }
+        };
+        let (columns, values): (Vec<_>, Vec<_>) = row.into_iter().unzip();
+
+        match &mut self.pending {
+            Some(Batch::Insert {
+                table: pending_table,
+                columns: pending_columns,
+                rows,
+            }) if *pending_table == table && *pending_columns == columns => {
+                rows.push(values)
+            }
+            pending => {
+                ready.extend(pending.take());
+                *pending = Some(Batch::Insert {
+                    table,
+                    columns,
+                    rows: vec![values],
+                });
+            }
+        }
+
+        if let Some(Batch::Insert { rows, .. }) = &self.pending {
+            if rows.len() >= self.size {
+                ready.extend(self.pending.take());
+            }
+        }
+
+        ready
+    }
+
+    /// Batch of the rows left after the last statement
+    pub fn finish(self) -> Option<Batch> {
+        self.pending
+    }
+}
@@ -57,3 +57,38 @@ fn test_option_conversion() {
     assert_eq!(SqlValue::from(some_str), SqlValue::Text("test".to_string()));
     assert_eq!(SqlValue::from(none_str), SqlValue::Null);
 }
+
+#[test]
+fn test_temporal_values() {
+    assert_eq!(
+        SqlValue::date("2024-01-31"),
+        SqlValue::Date("2024-01-31".to_string())
+    );
+    assert_eq!(
+        SqlValue::timestamp("2024-01-31 12:00:00"),
+        SqlValue::Timestamp("2024-01-31 12:00:00".to_string())
+    );
+    assert_eq!(SqlValue::date("2024-01-31").type_name(), "DATE");
+    assert_eq!(
+        SqlValue::timestamp("2024-01-31 12:00:00").to_string(),
+        "2024-01-31 12:00:00"
+    );
+}
+
+#[test]
+fn test_uuid_value() {
+    let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    assert_eq!(SqlValue::uuid(id), SqlValue::Uuid(id.to_string()));
+    assert_eq!(SqlValue::uuid(id).type_name(), "UUID");
+    assert_eq!(SqlValue::uuid(id).to_string(), id);
+}
+
+#[test]
+fn test_json_value() {
+    let json = r#"{"theme":"dark","tags":["a","b"]}"#;
+
+    assert_eq!(SqlValue::json(json), SqlValue::Json(json.to_string()));
+    assert_eq!(SqlValue::json(json).type_name(), "JSON");
+    assert_eq!(SqlValue::json(json).to_string(), json);
+}
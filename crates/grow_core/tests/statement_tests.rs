@@ -0,0 +1,116 @@
+use grow_core::{Batch, Batcher, SqlValue, Statement};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_statement_inserts() {
+    let mut tables = BTreeMap::new();
+    tables.insert(
+        "roles".to_string(),
+        vec![
+            vec![("name".to_string(), SqlValue::text("admin"))],
+            vec![("name".to_string(), SqlValue::text("guest"))],
+        ],
+    );
+    tables.insert("tags".to_string(), vec![]);
+
+    let statements = Statement::inserts(tables);
+
+    assert_eq!(statements.len(), 2);
+    assert_eq!(
+        statements[1],
+        Statement::Insert {
+            table: "roles".to_string(),
+            row: vec![("name".to_string(), SqlValue::text("guest"))],
+        }
+    );
+}
+
+#[test]
+fn test_statement_batches() {
+    let insert = |table: &str, columns: &[&str], id: i64| Statement::Insert {
+        table: table.to_string(),
+        row: columns
+            .iter()
+            .map(|column| (column.to_string(), SqlValue::Integer(id)))
+            .collect(),
+    };
+    let statements = vec![
+        insert("users", &["id"], 1),
+        insert("users", &["id"], 2),
+        insert("users", &["id"], 3),
+        insert("users", &["id", "age"], 4),
+        Statement::Sql("UPDATE users SET age = 1".to_string()),
+        insert("users", &["id"], 5),
+        insert("roles", &["id"], 6),
+    ];
+
+    let batches = Statement::batches(statements.clone(), 2);
+
+    assert_eq!(
+        batches,
+        vec![
+            Batch::Insert {
+                table: "users".to_string(),
+                columns: vec!["id".to_string()],
+                rows: vec![
+                    vec![SqlValue::Integer(1)],
+                    vec![SqlValue::Integer(2)],
+                ],
+            },
+            Batch::Insert {
+                table: "users".to_string(),
+                columns: vec!["id".to_string()],
+                rows: vec![vec![SqlValue::Integer(3)]],
+            },
+            Batch::Insert {
+                table: "users".to_string(),
+                columns: vec!["id".to_string(), "age".to_string()],
+                rows: vec![vec![SqlValue::Integer(4), SqlValue::Integer(4)]],
+            },
+            Batch::Sql("UPDATE users SET age = 1".to_string()),
+            Batch::Insert {
+                table: "users".to_string(),
+                columns: vec!["id".to_string()],
+                rows: vec![vec![SqlValue::Integer(5)]],
+            },
+            Batch::Insert {
+                table: "roles".to_string(),
+                columns: vec!["id".to_string()],
+                rows: vec![vec![SqlValue::Integer(6)]],
+            },
+        ]
+    );
+
+    // Without batching every statement runs on its own
+    assert_eq!(Statement::batches(statements.clone(), 1).len(), 7);
+    assert_eq!(Statement::batches(statements, 0).len(), 7);
+}
+
+#[test]
+fn test_batcher_flushes_full_batches() {
+    let insert = |id: i64| Statement::Insert {
+        table: "users".to_string(),
+        row: vec![("id".to_string(), SqlValue::Integer(id))],
+    };
+    let batch = |ids: &[i64]| Batch::Insert {
+        table: "users".to_string(),
+        columns: vec!["id".to_string()],
+        rows: ids.iter().map(|id| vec![SqlValue::Integer(*id)]).collect(),
+    };
+
+    let mut batcher = Batcher::new(2);
+
+    // A batch is ready as soon as it holds `size` rows
+    assert_eq!(batcher.push(insert(1)), vec![]);
+    assert_eq!(batcher.push(insert(2)), vec![batch(&[1, 2])]);
+    assert_eq!(batcher.push(insert(3)), vec![]);
+
+    // Raw SQL runs after the pending rows
+    assert_eq!(
+        batcher.push(Statement::Sql("DELETE FROM users".to_string())),
+        vec![batch(&[3]), Batch::Sql("DELETE FROM users".to_string())]
+    );
+
+    assert_eq!(batcher.push(insert(4)), vec![]);
+    assert_eq!(batcher.finish(), Some(batch(&[4])));
+}
@@ -1,9 +1,27 @@
-pub use grow_core::SqlValue;
+use futures::{stream, Stream, StreamExt};
+pub use grow_core::{Batch, Batcher, SqlValue, Statement};
 use std::collections::BTreeMap;
 
 pub async fn run_seeder(
     db_url: String,
     tables: BTreeMap<String, Vec<Vec<(String, SqlValue)>>>,
+) -> Result<(), String> {
+    run_statements(
+        db_url,
+        stream::iter(Statement::inserts(tables).into_iter().map(Ok)),
+        1,
+    )
+    .await
+}
+
+/// Runs the statements of a seeder in order, inside one transaction, with
+/// up to `batch_size` consecutive rows of a table in each insert. Each batch
+/// runs once it is full, so the statements are read as they are rendered;
+/// an error in the stream rolls the transaction back.
+pub async fn run_statements(
+    db_url: String,
+    statements: impl Stream<Item = Result<Statement, String>>,
+    batch_size: usize,
 ) -> Result<(), String> {
     let db_token = std::env::var("TURSO_AUTH_TOKEN").map_err(|err| {
         format!(
@@ -23,42 +41,61 @@ pub async fn run_seeder(
         .connect()
         .map_err(|err| format!("Could not connect to the database: {err}"))?;
 
-    for (table, rows) in tables {
-        for fields in rows {
-            insert_entry(&conn, &table, fields).await?
+    let transaction = conn
+        .transaction()
+        .await
+        .map_err(|err| format!("Could not start a transaction: {err}"))?;
+
+    let mut statements = std::pin::pin!(statements);
+    let mut batcher = Batcher::new(batch_size);
+
+    while let Some(statement) = statements.next().await {
+        for batch in batcher.push(statement?) {
+            run_batch(&transaction, batch).await?;
         }
     }
 
-    Ok(())
+    if let Some(batch) = batcher.finish() {
+        run_batch(&transaction, batch).await?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|err| format!("Could not commit the transaction: {err}"))
 }
 
-async fn insert_entry(
+async fn run_batch(
     conn: &libsql::Connection,
-    table: &str,
-    entry: Vec<(String, SqlValue)>,
+    batch: Batch,
 ) -> Result<(), String> {
-    let (columns, values) = entry.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
-
-    let escaped_table = escape_table_name(table);
-
-    let placeholders = (1..=values.len())
-        .map(|i| format!("?{}", i))
-        .collect::<Vec<_>>()
-        .join(", ");
+    match batch {
+        Batch::Insert {
+            table,
+            columns,
+            rows,
+        } => insert_rows(conn, &table, &columns, rows).await,
+        Batch::Sql(sql) => {
+            conn.execute(&sql, ()).await.map_err(|err| {
+                format!("Error executing query ({sql}): {err}")
+            })?;
+
+            Ok(())
+        }
+    }
+}
 
-    let sql_query = format!(
-        "INSERT INTO {} ({}) VALUES ({})",
-        escaped_table,
-        columns
-            .iter()
-            .map(|col| escape_column_name(col))
-            .collect::<Vec<_>>()
-            .join(", "),
-        placeholders
-    );
+async fn insert_rows(
+    conn: &libsql::Connection,
+    table: &str,
+    columns: &[String],
+    rows: Vec<Vec<SqlValue>>,
+) -> Result<(), String> {
+    let sql_query = insert_query(table, columns, rows.len());
 
-    let params: Vec<libsql::Value> = values
+    let params: Vec<libsql::Value> = rows
         .into_iter()
+        .flatten()
         .map(|v| match v {
             SqlValue::Integer(i) => libsql::Value::Integer(i),
             SqlValue::Float(f) => libsql::Value::Real(f),
@@ -66,6 +103,10 @@ async fn insert_entry(
             SqlValue::Boolean(b) => {
                 libsql::Value::Integer(if b { 1 } else { 0 })
             }
+            SqlValue::Date(d) => libsql::Value::Text(d),
+            SqlValue::Timestamp(t) => libsql::Value::Text(t),
+            SqlValue::Uuid(u) => libsql::Value::Text(u),
+            SqlValue::Json(j) => libsql::Value::Text(j),
             SqlValue::Null => libsql::Value::Null,
         })
         .collect();
@@ -77,6 +118,32 @@ async fn insert_entry(
     Ok(())
 }
 
+/// Insert of `rows` rows with a `?N` placeholder for each value
+pub fn insert_query(table: &str, columns: &[String], rows: usize) -> String {
+    let values = (0..rows)
+        .map(|row| {
+            let placeholders = (1..=columns.len())
+                .map(|i| format!("?{}", row * columns.len() + i))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("({placeholders})")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "INSERT INTO {} ({}) VALUES {}",
+        escape_table_name(table),
+        columns
+            .iter()
+            .map(|col| escape_column_name(col))
+            .collect::<Vec<_>>()
+            .join(", "),
+        values
+    )
+}
+
 pub fn escape_table_name(table: &str) -> String {
     if table.contains('.') {
         let parts: Vec<&str> = table.split('.').collect();
@@ -127,6 +194,10 @@ pub async fn execute_query(
             SqlValue::Boolean(b) => {
                 libsql::Value::Integer(if b { 1 } else { 0 })
             }
+            SqlValue::Date(d) => libsql::Value::Text(d),
+            SqlValue::Timestamp(t) => libsql::Value::Text(t),
+            SqlValue::Uuid(u) => libsql::Value::Text(u),
+            SqlValue::Json(j) => libsql::Value::Text(j),
             SqlValue::Null => libsql::Value::Null,
         })
         .collect();
@@ -213,6 +284,10 @@ pub async fn query_single_int(
             SqlValue::Boolean(b) => {
                 libsql::Value::Integer(if b { 1 } else { 0 })
             }
+            SqlValue::Date(d) => libsql::Value::Text(d),
+            SqlValue::Timestamp(t) => libsql::Value::Text(t),
+            SqlValue::Uuid(u) => libsql::Value::Text(u),
+            SqlValue::Json(j) => libsql::Value::Text(j),
             SqlValue::Null => libsql::Value::Null,
         })
         .collect();
@@ -1,4 +1,6 @@
-use grow_libsql::{escape_column_name, escape_table_name, SqlValue};
+use grow_libsql::{
+    escape_column_name, escape_table_name, insert_query, SqlValue,
+};
 
 #[test]
 fn test_escape_table_name() {
@@ -146,3 +148,17 @@ mod integration_tests {
         assert!(tables.contains_key("products"));
     }
 }
+
+#[test]
+fn test_insert_query() {
+    let columns = vec!["id".to_string(), "name".to_string()];
+
+    assert_eq!(
+        insert_query("users", &columns, 1),
+        "INSERT INTO \"users\" (\"id\", \"name\") VALUES (?1, ?2)"
+    );
+    assert_eq!(
+        insert_query("users", &columns, 3),
+        "INSERT INTO \"users\" (\"id\", \"name\") VALUES (?1, ?2), (?3, ?4), (?5, ?6)"
+    );
+}
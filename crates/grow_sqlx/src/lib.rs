@@ -1,6 +1,7 @@
-pub use grow_core::SqlValue;
-use sqlx::{AnyPool, Row};
-use std::collections::BTreeMap;
+use futures::{stream, Stream, StreamExt};
+pub use grow_core::{Batch, Batcher, SqlValue, Statement};
+use sqlx::{AnyConnection, AnyPool, Row};
+use std::collections::{BTreeMap, BTreeSet};
 
 pub type RenderedTable = Vec<Vec<(String, SqlValue)>>;
 
@@ -34,6 +35,29 @@ pub fn sql_value_from_external(external: ExternalSqlValue) -> SqlValue {
 pub async fn run_seeder(
     database_url: String,
     tables: BTreeMap<String, RenderedTable>,
+) -> Result<(), String> {
+    run_statements(
+        database_url,
+        stream::iter(Statement::inserts(tables).into_iter().map(Ok)),
+        &BTreeSet::new(),
+        1,
+    )
+    .await
+}
+
+/// Runs the statements of a seeder in order, inside one transaction, with
+/// up to `batch_size` consecutive rows of a table in each insert. Each batch
+/// runs once it is full, so the statements are read as they are rendered;
+/// an error in the stream rolls the transaction back.
+///
+/// On Postgres and MySQL the sequences of the columns given explicit values,
+/// as an `id`, are moved past the inserted values, except for the tables of
+/// `keep_sequences`.
+pub async fn run_statements(
+    database_url: String,
+    statements: impl Stream<Item = Result<Statement, String>>,
+    keep_sequences: &BTreeSet<String>,
+    batch_size: usize,
 ) -> Result<(), String> {
     // Install default drivers for AnyPool
     sqlx::any::install_default_drivers();
@@ -42,60 +66,298 @@ pub async fn run_seeder(
         format!("Cannot connect to database ({database_url}): {err}")
     })?;
 
-    for (table, data) in tables {
-        for entry in data {
-            insert_entry(&pool, &table, entry).await?;
+    let is_postgres = database_url.starts_with("postgres");
+    let is_mysql = database_url.starts_with("mysql")
+        || database_url.starts_with("mariadb");
+
+    let mut explicit_columns = BTreeMap::<String, BTreeSet<String>>::new();
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|err| format!("Cannot start a transaction: {err}"))?;
+
+    let mut statements = std::pin::pin!(statements);
+    let mut batcher = Batcher::new(batch_size);
+
+    while let Some(statement) = statements.next().await {
+        for batch in batcher.push(statement?) {
+            run_batch(
+                &mut transaction,
+                batch,
+                keep_sequences,
+                &mut explicit_columns,
+                is_postgres,
+            )
+            .await?;
+        }
+    }
+
+    if let Some(batch) = batcher.finish() {
+        run_batch(
+            &mut transaction,
+            batch,
+            keep_sequences,
+            &mut explicit_columns,
+            is_postgres,
+        )
+        .await?;
+    }
+
+    // MySQL commits implicitly before an `ALTER TABLE`, so its resets run
+    // after the seeder is committed
+    let mut mysql_resets = Vec::new();
+
+    if is_postgres || is_mysql {
+        for (table, columns) in explicit_columns {
+            for column in
+                sequence_columns(&mut transaction, &table, is_postgres).await?
+            {
+                if !columns.contains(&column) {
+                    continue;
+                }
+
+                let query = reset_sequence_query(&table, &column, is_postgres);
+
+                if is_mysql {
+                    mysql_resets.push((table.clone(), column, query));
+                    continue;
+                }
+
+                sqlx::raw_sql(&query)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(|err| {
+                        format!("Cannot reset the sequence of {table}.{column} ({query}): {err}")
+                    })?;
+            }
         }
     }
 
+    transaction
+        .commit()
+        .await
+        .map_err(|err| format!("Cannot commit the transaction: {err}"))?;
+
+    for (table, column, query) in mysql_resets {
+        sqlx::raw_sql(&query).execute(&pool).await.map_err(|err| {
+            format!("Cannot reset the sequence of {table}.{column} ({query}): {err}")
+        })?;
+    }
+
     Ok(())
 }
 
-async fn insert_entry(
-    pool: &AnyPool,
-    table: &str,
-    entry: Vec<(String, SqlValue)>,
+/// Runs a batch, keeping the columns given explicit values by table
+async fn run_batch(
+    connection: &mut AnyConnection,
+    batch: Batch,
+    keep_sequences: &BTreeSet<String>,
+    explicit_columns: &mut BTreeMap<String, BTreeSet<String>>,
+    is_postgres: bool,
 ) -> Result<(), String> {
-    let (columns, values) = entry.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+    match batch {
+        Batch::Insert {
+            table,
+            columns,
+            rows,
+        } => {
+            if !keep_sequences.contains(&table) {
+                explicit_columns
+                    .entry(table.clone())
+                    .or_default()
+                    .extend(columns.iter().cloned());
+            }
 
-    let escaped_table = escape_table_name(table);
+            insert_rows(connection, &table, &columns, rows, is_postgres).await
+        }
+        Batch::Sql(sql) => {
+            sqlx::raw_sql(&sql)
+                .execute(connection)
+                .await
+                .map_err(|err| {
+                    format!("Cannot execute query ({sql}): {err}")
+                })?;
 
-    let placeholders = (1..=values.len())
-        .map(|i| format!("${}", i))
-        .collect::<Vec<_>>()
-        .join(", ");
+            Ok(())
+        }
+    }
+}
 
-    let sql_query = format!(
-        "INSERT INTO {} ({}) VALUES ({})",
-        escaped_table,
-        columns
-            .iter()
-            .map(|col| escape_column_name(col))
+/// Columns of a table filled by a sequence, `SERIAL` and `IDENTITY` columns
+/// on Postgres and the `AUTO_INCREMENT` column on MySQL
+async fn sequence_columns(
+    connection: &mut AnyConnection,
+    table: &str,
+    is_postgres: bool,
+) -> Result<Vec<String>, String> {
+    let (schema, table_name) = match table.split_once('.') {
+        Some((schema, table_name)) => (Some(schema), table_name),
+        None => (None, table),
+    };
+
+    let query =
+        if is_postgres {
+            format!(
+            "SELECT CAST(column_name AS TEXT) FROM information_schema.columns \
+            WHERE table_name = $1 AND table_schema = {} \
+            AND (column_default LIKE 'nextval(%' OR is_identity = 'YES')",
+            if schema.is_some() { "$2" } else { "current_schema()" }
+        )
+        } else {
+            format!(
+                "SELECT column_name FROM information_schema.columns \
+            WHERE table_name = ? AND table_schema = {} \
+            AND extra LIKE '%auto_increment%'",
+                if schema.is_some() { "?" } else { "DATABASE()" }
+            )
+        };
+
+    let mut query = sqlx::query(&query).bind(table_name);
+    if let Some(schema) = schema {
+        query = query.bind(schema);
+    }
+
+    let rows = query.fetch_all(connection).await.map_err(|err| {
+        format!("Cannot read the sequences of {table}: {err}")
+    })?;
+
+    rows.iter()
+        .map(|row| {
+            row.try_get::<String, _>(0).map_err(|err| {
+                format!("Cannot read the sequences of {table}: {err}")
+            })
+        })
+        .collect()
+}
+
+/// Query moving the sequence of a column past its greatest value.
+///
+/// `pg_get_serial_sequence` takes the column name as is, so it is given as a
+/// plain string literal. MySQL moves `AUTO_INCREMENT` to the greatest value
+/// plus one when it is set lower than the values in use.
+pub fn reset_sequence_query(
+    table: &str,
+    column: &str,
+    is_postgres: bool,
+) -> String {
+    if is_postgres {
+        let escaped_table = escape_table_name(table);
+        let escaped_column = escape_column_name(column);
+
+        format!(
+            "SELECT setval(pg_get_serial_sequence('{}', '{}'), MAX({escaped_column})) \
+            FROM {escaped_table} HAVING MAX({escaped_column}) IS NOT NULL",
+            escaped_table.replace('\'', "''"),
+            column.replace('\'', "''"),
+        )
+    } else {
+        let escaped_table = table
+            .split('.')
+            .map(|part| format!("`{}`", part.replace('`', "``")))
             .collect::<Vec<_>>()
-            .join(", "),
-        placeholders
-    );
+            .join(".");
+
+        format!("ALTER TABLE {escaped_table} AUTO_INCREMENT = 1")
+    }
+}
+
+async fn insert_rows(
+    connection: &mut AnyConnection,
+    table: &str,
+    columns: &[String],
+    rows: Vec<Vec<SqlValue>>,
+    is_postgres: bool,
+) -> Result<(), String> {
+    let sql_query = insert_query(table, columns, &rows, is_postgres);
 
     let mut query = sqlx::query(&sql_query);
 
-    for value in values {
+    for value in rows.into_iter().flatten() {
         query = match value {
             SqlValue::Integer(i) => query.bind(i),
             SqlValue::Float(f) => query.bind(f),
             SqlValue::Text(s) => query.bind(s),
             SqlValue::Boolean(b) => query.bind(b),
+            SqlValue::Date(d) => query.bind(d),
+            SqlValue::Timestamp(t) => query.bind(t),
+            SqlValue::Uuid(u) => query.bind(u),
+            SqlValue::Json(j) => query.bind(j),
             SqlValue::Null => query.bind(Option::<String>::None),
         };
     }
 
     query
-        .execute(pool)
+        .execute(connection)
         .await
         .map_err(|err| format!("Cannot execute query ({sql_query}): {err}"))?;
 
     Ok(())
 }
 
+/// Insert of `rows` with a placeholder for each value, numbered across the
+/// rows
+pub fn insert_query(
+    table: &str,
+    columns: &[String],
+    rows: &[Vec<SqlValue>],
+    is_postgres: bool,
+) -> String {
+    let values = rows
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let placeholders = row
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    placeholder(
+                        row_index * row.len() + i + 1,
+                        value,
+                        is_postgres,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("({placeholders})")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "INSERT INTO {} ({}) VALUES {}",
+        escape_table_name(table),
+        columns
+            .iter()
+            .map(|col| escape_column_name(col))
+            .collect::<Vec<_>>()
+            .join(", "),
+        values
+    )
+}
+
+/// Builds the placeholder for a bound value.
+///
+/// The `Any` driver only binds primitives, so temporal values, UUIDs and JSON
+/// are sent as text and cast on Postgres, which does not coerce text
+/// parameters.
+pub fn placeholder(
+    index: usize,
+    value: &SqlValue,
+    is_postgres: bool,
+) -> String {
+    match value {
+        SqlValue::Date(_) if is_postgres => format!("CAST(${index} AS DATE)"),
+        SqlValue::Timestamp(_) if is_postgres => {
+            format!("CAST(${index} AS TIMESTAMP)")
+        }
+        SqlValue::Uuid(_) if is_postgres => format!("CAST(${index} AS UUID)"),
+        SqlValue::Json(_) if is_postgres => format!("CAST(${index} AS JSONB)"),
+        _ => format!("${index}"),
+    }
+}
+
 pub fn escape_table_name(table: &str) -> String {
     if table.contains('.') {
         let parts: Vec<&str> = table.split('.').collect();
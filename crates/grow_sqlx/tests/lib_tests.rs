@@ -1,6 +1,7 @@
 use grow_sqlx::{
-    escape_column_name, escape_table_name, sql_value_from_external,
-    ExternalSqlValue, RenderedTable, SqlValue,
+    escape_column_name, escape_table_name, insert_query, placeholder,
+    reset_sequence_query, sql_value_from_external, ExternalSqlValue,
+    RenderedTable, SqlValue,
 };
 use std::collections::BTreeMap;
 
@@ -44,6 +45,66 @@ fn test_escape_column_name() {
     assert_eq!(escape_column_name(""), "\"\"");
 }
 
+#[test]
+fn test_placeholder() {
+    let date = SqlValue::Date("2024-01-31".to_string());
+    let timestamp = SqlValue::Timestamp("2024-01-31 12:00:00".to_string());
+
+    assert_eq!(placeholder(1, &SqlValue::Integer(1), true), "$1");
+    assert_eq!(placeholder(2, &date, true), "CAST($2 AS DATE)");
+    assert_eq!(placeholder(3, &timestamp, true), "CAST($3 AS TIMESTAMP)");
+
+    let id = SqlValue::Uuid("67e55044-10b1-426f-9247-bb680e5fe0c8".into());
+    assert_eq!(placeholder(4, &id, true), "CAST($4 AS UUID)");
+    assert_eq!(placeholder(4, &id, false), "$4");
+
+    let json = SqlValue::Json(r#"{"theme":"dark"}"#.into());
+    assert_eq!(placeholder(5, &json, true), "CAST($5 AS JSONB)");
+    assert_eq!(placeholder(5, &json, false), "$5");
+
+    // Other backends coerce text into temporal columns themselves
+    assert_eq!(placeholder(2, &date, false), "$2");
+    assert_eq!(placeholder(3, &timestamp, false), "$3");
+}
+
+#[test]
+fn test_reset_sequence_query() {
+    assert_eq!(
+        reset_sequence_query("auth.users", "id", true),
+        "SELECT setval(pg_get_serial_sequence('\"auth\".\"users\"', 'id'), MAX(\"id\")) \
+        FROM \"auth\".\"users\" HAVING MAX(\"id\") IS NOT NULL"
+    );
+    assert_eq!(
+        reset_sequence_query("user's", "O'Brien Id", true),
+        "SELECT setval(pg_get_serial_sequence('\"user''s\"', 'O''Brien Id'), MAX(\"O'Brien Id\")) \
+        FROM \"user's\" HAVING MAX(\"O'Brien Id\") IS NOT NULL"
+    );
+    assert_eq!(
+        reset_sequence_query("users", "id", false),
+        "ALTER TABLE `users` AUTO_INCREMENT = 1"
+    );
+    assert_eq!(
+        reset_sequence_query("shop.order`s", "id", false),
+        "ALTER TABLE `shop`.`order``s` AUTO_INCREMENT = 1"
+    );
+}
+
+#[test]
+fn test_insert_query() {
+    let columns = vec!["id".to_string(), "born".to_string()];
+    let row = |id| vec![SqlValue::Integer(id), SqlValue::date("2000-01-01")];
+
+    assert_eq!(
+        insert_query("users", &columns, &[row(1)], false),
+        "INSERT INTO \"users\" (\"id\", \"born\") VALUES ($1, $2)"
+    );
+    assert_eq!(
+        insert_query("auth.users", &columns, &[row(1), row(2)], true),
+        "INSERT INTO \"auth\".\"users\" (\"id\", \"born\") \
+        VALUES ($1, CAST($2 AS DATE)), ($3, CAST($4 AS DATE))"
+    );
+}
+
 #[test]
 fn test_sql_value_from_external_integer() {
     let external = ExternalSqlValue {
@@ -1,4 +1,5 @@
-pub use grow_core::SqlValue;
+use futures::{stream, Stream, StreamExt};
+pub use grow_core::{SqlValue, Statement};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use surrealdb::engine::any::{connect, Any};
@@ -40,6 +41,30 @@ pub async fn run_seeder(
     config: SurrealConfig,
     tables: BTreeMap<String, RenderedTable>,
 ) -> Result<(), String> {
+    run_statements(
+        config,
+        stream::iter(Statement::inserts(tables).into_iter().map(Ok)),
+    )
+    .await
+}
+
+/// Runs the statements of a seeder in order, inside one transaction. A
+/// transaction is a single query on SurrealDB, so each statement is turned
+/// into its part of the query as it comes and the query runs at the end.
+pub async fn run_statements(
+    config: SurrealConfig,
+    statements: impl Stream<Item = Result<Statement, String>>,
+) -> Result<(), String> {
+    let mut statements = std::pin::pin!(statements);
+    let mut queries = vec!["BEGIN TRANSACTION;".to_string()];
+
+    while let Some(statement) = statements.next().await {
+        queries.push(statement_query(&statement?)?);
+    }
+
+    queries.push("COMMIT TRANSACTION;".to_string());
+    let query = queries.join("\n");
+
     // Connect to SurrealDB
     let db: Surreal<Any> = connect(&config.endpoint).await.map_err(|err| {
         format!("Cannot connect to SurrealDB ({}): {}", config.endpoint, err)
@@ -62,12 +87,10 @@ pub async fn run_seeder(
             format!("Failed to select namespace/database: {}", err)
         })?;
 
-    // Insert data for each table
-    for (table_name, rows) in tables {
-        for row in rows {
-            insert_record(&db, &table_name, row).await?;
-        }
-    }
+    db.query(&query)
+        .await
+        .and_then(|response| response.check())
+        .map_err(|err| format!("Failed to run the seeder: {}", err))?;
 
     Ok(())
 }
@@ -82,20 +105,38 @@ pub async fn run_seeder_with_connection_string(
     run_seeder(config, owned_tables).await
 }
 
-async fn insert_record(
-    db: &Surreal<Any>,
-    table: &str,
-    entry: Vec<(String, SqlValue)>,
+/// Runs the statements of a seeder on SurrealDB with connection string
+pub async fn run_statements_with_connection_string(
+    connection_string: &str,
+    statements: impl Stream<Item = Result<Statement, String>>,
 ) -> Result<(), String> {
-    // Build SurrealDB query manually to avoid serialization issues
-    let query = build_insert_query(table, &entry)?;
+    let config = parse_connection_string(connection_string)?;
+    run_statements(config, statements).await
+}
 
-    // Execute raw query
-    let _result = db.query(&query).await.map_err(|err| {
-        format!("Failed to create record in table {}: {}", table, err)
-    })?;
+/// Build the query running all the statements in one transaction
+pub fn build_transaction_query(
+    statements: &[Statement],
+) -> Result<String, String> {
+    let mut queries = vec!["BEGIN TRANSACTION;".to_string()];
 
-    Ok(())
+    for statement in statements {
+        queries.push(statement_query(statement)?);
+    }
+
+    queries.push("COMMIT TRANSACTION;".to_string());
+
+    Ok(queries.join("\n"))
+}
+
+/// Part of the transaction query running a statement
+fn statement_query(statement: &Statement) -> Result<String, String> {
+    match statement {
+        Statement::Insert { table, row } => build_insert_query(table, row),
+        Statement::Sql(sql) => {
+            Ok(format!("{};", sql.trim().trim_end_matches(';')))
+        }
+    }
 }
 
 /// Build SurrealDB CREATE query from row data
@@ -113,6 +154,10 @@ fn build_insert_query(
             SqlValue::Float(f) => f.to_string(),
             SqlValue::Text(s) => format!("\"{}\"", s.replace('"', "\\\"")),
             SqlValue::Boolean(b) => b.to_string(),
+            SqlValue::Date(d) => datetime_literal(d),
+            SqlValue::Timestamp(t) => datetime_literal(t),
+            SqlValue::Uuid(u) => format!("u\"{u}\""),
+            SqlValue::Json(j) => j.clone(),
             SqlValue::Null => "NONE".to_string(),
         };
         // Use = instead of : for SurrealDB CREATE queries
@@ -123,6 +168,27 @@ fn build_insert_query(
     Ok(query)
 }
 
+/// Build a SurrealDB datetime literal from a `YYYY-MM-DD` date or a
+/// `YYYY-MM-DD HH:MM:SS` timestamp
+pub fn datetime_literal(value: &str) -> String {
+    let datetime = match value.split_once(' ') {
+        Some((date, time)) => format!("{date}T{time}"),
+        None if value.contains('T') => value.to_string(),
+        None => format!("{value}T00:00:00"),
+    };
+
+    let has_offset = datetime.ends_with('Z')
+        || datetime
+            .get(10..)
+            .is_some_and(|time| time.contains(['+', '-']));
+
+    if has_offset {
+        format!("d\"{datetime}\"")
+    } else {
+        format!("d\"{datetime}Z\"")
+    }
+}
+
 // Helper function to parse connection strings
 pub fn parse_connection_string(
     connection_string: &str,
@@ -264,6 +330,10 @@ pub fn build_bulk_insert_query(
                 SqlValue::Float(f) => f.to_string(),
                 SqlValue::Text(s) => format!("\"{}\"", s.replace('\"', "\\\"")),
                 SqlValue::Boolean(b) => b.to_string(),
+                SqlValue::Date(d) => datetime_literal(d),
+                SqlValue::Timestamp(t) => datetime_literal(t),
+                SqlValue::Uuid(u) => format!("u\"{u}\""),
+                SqlValue::Json(j) => j.clone(),
                 SqlValue::Null => "NONE".to_string(),
             };
             fields.push(format!("{} = {}", key, value_str));
@@ -292,7 +362,7 @@ mod tests {
         ];
 
         for (name, sql_val) in test_cases {
-            // Simulate the conversion that happens in insert_record
+            // Simulate the conversion that happens in build_insert_query
             let result = match sql_val {
                 SqlValue::Integer(i) => {
                     serde_json::from_value::<surrealdb::Value>(
@@ -322,6 +392,15 @@ mod tests {
                     )
                     .is_ok()
                 }
+                SqlValue::Date(d)
+                | SqlValue::Timestamp(d)
+                | SqlValue::Uuid(d)
+                | SqlValue::Json(d) => {
+                    serde_json::from_value::<surrealdb::Value>(
+                        serde_json::Value::String(d),
+                    )
+                    .is_ok()
+                }
                 SqlValue::Null => serde_json::from_value::<surrealdb::Value>(
                     serde_json::Value::Null,
                 )
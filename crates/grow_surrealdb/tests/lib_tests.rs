@@ -1,6 +1,7 @@
 use grow_surrealdb::{
-    build_bulk_insert_query, parse_connection_string, validate_table_name,
-    RenderedTable, SqlValue, SurrealConfig,
+    build_bulk_insert_query, build_transaction_query, datetime_literal,
+    parse_connection_string, validate_table_name, RenderedTable, SqlValue,
+    Statement, SurrealConfig,
 };
 use std::collections::BTreeMap;
 
@@ -132,6 +133,46 @@ fn test_build_bulk_insert_query_all_sql_value_types() {
     assert_eq!(query, expected);
 }
 
+#[test]
+fn test_build_bulk_insert_query_temporal_values() {
+    let rows = vec![vec![
+        ("born".to_string(), SqlValue::Date("1990-05-04".to_string())),
+        (
+            "created_at".to_string(),
+            SqlValue::Timestamp("2024-01-31 12:30:00".to_string()),
+        ),
+    ]];
+
+    let query = build_bulk_insert_query("people", &rows).unwrap();
+    let expected = "CREATE people SET born = d\"1990-05-04T00:00:00Z\", created_at = d\"2024-01-31T12:30:00Z\";";
+    assert_eq!(query, expected);
+}
+
+#[test]
+fn test_build_bulk_insert_query_uuid_value() {
+    let rows = vec![vec![(
+        "id".to_string(),
+        SqlValue::Uuid("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()),
+    )]];
+
+    let query = build_bulk_insert_query("users", &rows).unwrap();
+    let expected =
+        "CREATE users SET id = u\"67e55044-10b1-426f-9247-bb680e5fe0c8\";";
+    assert_eq!(query, expected);
+}
+
+#[test]
+fn test_datetime_literal_keeps_offset() {
+    assert_eq!(
+        datetime_literal("2024-01-31T12:30:00+02:00"),
+        "d\"2024-01-31T12:30:00+02:00\""
+    );
+    assert_eq!(
+        datetime_literal("2024-01-31T12:30:00Z"),
+        "d\"2024-01-31T12:30:00Z\""
+    );
+}
+
 #[test]
 fn test_build_bulk_insert_query_with_quotes_in_text() {
     let rows = vec![vec![(
@@ -155,6 +196,27 @@ fn test_build_bulk_insert_query_invalid_table_name() {
         .contains("must start with a letter or underscore"));
 }
 
+#[test]
+fn test_build_transaction_query() {
+    let statements = vec![
+        Statement::Insert {
+            table: "users".to_string(),
+            row: vec![(
+                "name".to_string(),
+                SqlValue::Text("Alice".to_string()),
+            )],
+        },
+        Statement::Sql("UPDATE users SET active = true;".to_string()),
+    ];
+
+    let query = build_transaction_query(&statements).unwrap();
+    let expected = "BEGIN TRANSACTION;\n\
+        CREATE users SET name = \"Alice\";\n\
+        UPDATE users SET active = true;\n\
+        COMMIT TRANSACTION;";
+    assert_eq!(query, expected);
+}
+
 #[test]
 fn test_surreal_config_default() {
     let config = SurrealConfig::default();
@@ -0,0 +1,24 @@
+use crate::utils;
+
+/// Prints each setting of grow.toml resolved for the active environment,
+/// with where it comes from
+pub fn show_config() {
+    let config = utils::config::current();
+
+    match &config.file {
+        Some(path) => println!("Config file: {}", path.display()),
+        None => println!(
+            "Config file: no {} found, using the defaults",
+            utils::config::FILE_NAME
+        ),
+    }
+
+    println!("\n{:<24} {:<40} Source", "Setting", "Value");
+    println!("{}", "-".repeat(80));
+
+    for (name, value, source) in config.entries() {
+        println!("{name:<24} {value:<40} {source}");
+    }
+
+    println!();
+}
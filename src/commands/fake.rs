@@ -0,0 +1,107 @@
+use crate::commands::run::fake::locale_index;
+use crate::commands::run::fake_generated::{
+    execute_faker, Faker, FAKERS, LOCALES,
+};
+
+/// Longest sample printed by `grow fake list`
+const SAMPLE_WIDTH: usize = 48;
+
+pub fn list_fakers(locale: Option<&str>, search: Option<&str>) {
+    let locale = locale.map(locale_index).unwrap_or_default();
+    let search = search.map(str::to_lowercase);
+
+    let fakers = FAKERS
+        .iter()
+        .filter(|faker| match &search {
+            Some(search) => {
+                faker.name.to_lowercase().contains(search)
+                    || faker.module.contains(search.as_str())
+            }
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    if fakers.is_empty() {
+        println!("No fake kinds found.");
+        return;
+    }
+
+    println!("\n{:<32} {:<16} Sample", "Kind", "Module");
+    println!("{}", "-".repeat(32 + 16 + SAMPLE_WIDTH + 2));
+
+    for faker in fakers {
+        // Kinds with arguments show how to call them instead
+        let sample = if faker.arguments.is_empty() {
+            execute_faker(faker.kind_in(locale), &[])
+                .map(|sample| truncate(&sample))
+                .unwrap_or_else(|err| err)
+        } else {
+            faker.signature()
+        };
+
+        let sample = match faker.only {
+            Some(only) => format!("{sample} (only {only})"),
+            None => sample,
+        };
+
+        println!(
+            "{:<32} {:<16} {}",
+            faker.name_in(locale),
+            faker.module,
+            sample
+        );
+    }
+
+    println!();
+}
+
+pub fn sample_faker(
+    name: &str,
+    count: usize,
+    args: &[String],
+) -> Result<(), String> {
+    for value in sample_values(name, count, args)? {
+        println!("{value}");
+    }
+
+    Ok(())
+}
+
+/// `count` values of a kind such as `FREE_EMAIL` or `FIRST_NAME_JA_JP`
+pub fn sample_values(
+    name: &str,
+    count: usize,
+    args: &[String],
+) -> Result<Vec<String>, String> {
+    let (faker, kind) = find_kind(name).ok_or_else(|| {
+        format!("Unknown fake kind `{name}`, see `grow fake list`")
+    })?;
+
+    (0..count)
+        .map(|_| {
+            execute_faker(kind, args)
+                .map_err(|err| format!("{err}, usage: {}", faker.signature()))
+        })
+        .collect()
+}
+
+fn find_kind(name: &str) -> Option<(&'static Faker, u16)> {
+    let name = name.trim().to_uppercase();
+
+    FAKERS.iter().find_map(|faker| {
+        (0..LOCALES.len())
+            .find(|locale| faker.name_in(*locale) == name)
+            .map(|locale| (faker, faker.kind_in(locale)))
+    })
+}
+
+fn truncate(sample: &str) -> String {
+    let sample = sample.replace('\n', " ");
+
+    if sample.chars().count() <= SAMPLE_WIDTH {
+        return sample;
+    }
+
+    let truncated = sample.chars().take(SAMPLE_WIDTH - 3).collect::<String>();
+    format!("{truncated}...")
+}
@@ -1,28 +1,30 @@
-use std::{fs, path::Path};
+use std::fs;
+
+use crate::utils;
 
 pub fn ensure_seeders_dir() {
-    let path = Path::new("seeders");
+    let path = utils::config::current().seeders.value;
     if !path.exists() {
-        if let Err(e) = fs::create_dir(path) {
+        if let Err(e) = fs::create_dir_all(&path) {
             eprintln!(
-                "Error: Unable to create 'seeders' directory. Reason: {e}"
+                "Error: Unable to create {path:#?} directory. Reason: {e}"
             );
         }
     }
 }
 
 pub fn init_seeder() {
-    let path = Path::new("seeders");
+    let path = utils::config::current().seeders.value;
 
     if path.exists() {
-        println!("The 'seeders' directory already exists. No action taken.");
+        println!("The {path:#?} directory already exists. No action taken.");
         return;
     }
 
-    match fs::create_dir(path) {
-        Ok(_) => println!("Successfully created the 'seeders' directory."),
+    match fs::create_dir_all(&path) {
+        Ok(_) => println!("Successfully created the {path:#?} directory."),
         Err(e) => eprintln!(
-            "Error: Unable to create 'seeders' directory. Reason: {e}"
+            "Error: Unable to create {path:#?} directory. Reason: {e}"
         ),
     }
 }
@@ -1,31 +1,18 @@
-use std::fs;
+use crate::utils;
 
 pub fn list_seeders() {
-    let seeder_dir = "./seeders";
+    let seeder_dir = utils::config::current().seeders.value;
 
-    match fs::read_dir(seeder_dir) {
-        Ok(entries) => {
-            let files = entries
-                .filter_map(Result::ok)
-                .filter(|entry| entry.path().is_file())
-                .filter_map(|entry| {
-                    entry.file_name().to_string_lossy().to_string();
-                    // Remove the ".ron" extension
-                    let path = entry.path();
-                    if path.extension()?.to_str()? == "ron" {
-                        Some(path.file_stem()?.to_string_lossy().to_string())
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<String>>();
+    match utils::seeder_names(&seeder_dir) {
+        Ok(seeders) => {
+            utils::warn_unprefixed(&seeders);
 
-            if files.is_empty() {
+            if seeders.is_empty() {
                 println!("No seeders available.");
             } else {
                 println!("Available seeders:");
-                for file in files {
-                    println!("- {}", file);
+                for (depth, line, _) in utils::seeder_tree(&seeders) {
+                    println!("{}- {}", "  ".repeat(depth), line);
                 }
             }
         }
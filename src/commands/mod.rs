@@ -1,12 +1,18 @@
+pub mod config;
+#[cfg(feature = "fake")]
+pub mod fake;
 pub mod init;
 pub mod list;
 pub mod new;
 pub mod run;
 pub mod status;
 
+pub use config::show_config;
+#[cfg(feature = "fake")]
+pub use fake::{list_fakers, sample_faker};
 pub use init::ensure_seeders_dir;
 pub use init::init_seeder;
 pub use list::list_seeders;
 pub use new::create_seeder;
-pub use run::run_seeder;
+pub use run::{run_seeder, RunOptions};
 pub use status::list_seeders_status;
@@ -4,16 +4,26 @@ use std::{
     path::Path,
 };
 
+use crate::utils;
+
 pub fn create_seeder(name: &str) {
-    let dir = Path::new("seeders");
+    let dir = utils::config::current().seeders.value;
+    // A name such as `demo/users` creates the seeder in a subfolder
+    let (folder, name) = name.rsplit_once('/').unwrap_or(("", name));
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    let file_path = dir.join(format!("{timestamp}_{name}.ron"));
+    let seeder_file = Path::new(folder).join(format!("{timestamp}_{name}.ron"));
+    let file_path = dir.join(&seeder_file);
 
     if !dir.exists() {
-        eprintln!("Error: The 'seeders' directory does not exist.");
+        eprintln!("Error: The {dir:#?} directory does not exist.");
+        return;
+    }
+
+    if let Err(e) = fs::create_dir_all(dir.join(folder)) {
+        eprintln!("Error: Unable to create the folder {folder}. Reason: {e}");
         return;
     }
 
@@ -47,7 +57,8 @@ pub fn create_seeder(name: &str) {
                 return;
             }
             println!(
-                "Successfully created the seeder file: {timestamp}_{name}.ron"
+                "Successfully created the seeder file: {}",
+                seeder_file.display()
             );
         }
         Err(e) => eprintln!("Error: Unable to create file. Reason: {e}"),
@@ -1,60 +1,154 @@
+pub mod clock;
+pub mod connection;
+pub mod data_file;
+pub mod datetime;
 pub mod drivers;
 pub mod entry;
+pub mod environment;
+pub mod expression;
 #[cfg(feature = "fake")]
 pub mod fake;
 #[cfg(feature = "fake")]
 pub mod fake_generated;
+pub mod hash;
+pub mod identifier;
 pub mod query;
+#[cfg(feature = "fake")]
+pub mod random;
+pub mod requirements;
+pub mod seeder_format;
 pub mod seeder_tracker;
+pub mod sequence;
+pub mod sql;
 pub mod template;
+pub mod unique;
+pub mod variables;
 
-use grow_core::SqlValue;
-use std::collections::BTreeMap;
-use std::env;
+use futures::channel::mpsc;
+use futures::StreamExt;
+use grow_core::Statement;
 use std::error::Error;
 use std::str::FromStr;
 
 use crate::utils;
+use connection::Trackers;
 use drivers::SchemeDriver;
-use entry::Entry;
+use entry::Seeder;
 use inquire::MultiSelect;
-use seeder_tracker::SeederTracker;
+
+/// Flags of `grow run`
+#[derive(Debug, Default)]
+pub struct RunOptions<'a> {
+    /// Run all the pending seeders
+    pub all: bool,
+    /// Run the pending seeders of a subfolder
+    pub group: Option<&'a str>,
+    /// Frozen time of `now()` and the other time functions
+    pub now: Option<&'a str>,
+    /// Assignments of `--var key=value`
+    pub vars: &'a [String],
+    /// Run a seeder outside of the environments of its `#![env(...)]`
+    pub force: bool,
+}
 
 pub async fn run_seeder(
     file_name: Option<&String>,
-    all: bool,
+    options: RunOptions<'_>,
 ) -> Result<(), Box<dyn Error>> {
-    if all {
-        return run_all_pending_seeders().await;
+    let RunOptions {
+        all,
+        group,
+        now,
+        vars,
+        force,
+    } = options;
+    // `--env` and `--locale` are resolved with grow.toml and its variables
+    let config = utils::config::current();
+    let active_env = config.environment.value;
+
+    if let Some(now) = now {
+        datetime::freeze_clock(Some(datetime::parse_timestamp(now)?));
+    }
+
+    variables::set_overrides(
+        vars.iter()
+            .map(|var| variables::parse_assignment(var))
+            .collect::<Result<_, _>>()?,
+    );
+
+    #[cfg(feature = "fake")]
+    if let Some(locale) = &config.locale.value {
+        fake::set_run_locale(locale);
+    }
+    #[cfg(not(feature = "fake"))]
+    if config.locale.source == utils::config::Source::Flag("--locale") {
+        return Err("--locale is only available with the fake feature".into());
     }
 
-    if file_name.is_none() {
-        return run_seeder_interactive().await;
+    if all || group.is_some() {
+        return run_all_pending_seeders(group, active_env.as_deref()).await;
     }
 
-    run_single_seeder_with_tracking(file_name).await
+    let Some(name) = file_name else {
+        return run_seeder_interactive(active_env.as_deref()).await;
+    };
+
+    if !force {
+        let root = utils::get_seeders().await?;
+        environment::check(
+            &root,
+            seeder_format::seeder_name(name),
+            active_env.as_deref(),
+        )?;
+    }
+
+    let mut trackers = Trackers::new(utils::config::current());
+    run_single_seeder_with_tracking(
+        &mut trackers,
+        file_name,
+        active_env.as_deref(),
+        force,
+    )
+    .await
 }
 
-pub async fn run_all_pending_seeders() -> Result<(), Box<dyn Error>> {
-    let seeders = utils::list_seeders().await?;
+/// Runs the pending seeders of the active environment, only those inside
+/// the `group` folder if given
+pub async fn run_all_pending_seeders(
+    group: Option<&str>,
+    active_env: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let root = utils::get_seeders().await?;
+    let names = utils::seeder_names(&root)?;
+    utils::warn_unprefixed(&names);
+    let mut seeders = environment::filter(&root, names, active_env);
+
+    if let Some(group) = group {
+        let prefix = format!("{}/", group.trim_end_matches('/'));
+        seeders.retain(|seeder| seeder.starts_with(&prefix));
+
+        if seeders.is_empty() {
+            println!("No seeders available in the group `{group}`.");
+            return Ok(());
+        }
+    }
 
     if seeders.is_empty() {
         println!("No seeders available in the seeders directory.");
         return Ok(());
     }
 
-    // Create tracker to check seeder status
-    let database_url = env::var("DATABASE_URL").map_err(|_| {
-        "Please, be sure to set the `DATABASE_URL` environment variable."
-    })?;
-
-    let tracker = SeederTracker::new(database_url)?;
-    tracker.ensure_seeds_table().await?;
+    // Each seeder is tracked in the database of its connection
+    let mut trackers = Trackers::new(utils::config::current());
 
     // Filter to get only pending seeders
     let mut pending_seeders = Vec::new();
     for seeder in seeders {
-        let is_executed = tracker.is_seeder_executed(&seeder).await?;
+        let is_executed = trackers
+            .of_seeder(&root, &seeder)
+            .await?
+            .is_seeder_executed(&seeder)
+            .await?;
         if !is_executed {
             pending_seeders.push(seeder);
         }
@@ -70,8 +164,18 @@ pub async fn run_all_pending_seeders() -> Result<(), Box<dyn Error>> {
     let mut success_count = 0;
     let mut error_count = 0;
 
+    // Seeders run after the seeders they require
+    let pending_seeders = requirements::order(&root, pending_seeders)?;
+
     for seeder_name in pending_seeders {
-        match run_single_seeder_with_tracking(Some(&seeder_name)).await {
+        match run_single_seeder_with_tracking(
+            &mut trackers,
+            Some(&seeder_name),
+            active_env,
+            false,
+        )
+        .await
+        {
             Ok(_) => {
                 success_count += 1;
             }
@@ -101,26 +205,30 @@ pub async fn run_all_pending_seeders() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-pub async fn run_seeder_interactive() -> Result<(), Box<dyn Error>> {
-    let seeders = utils::list_seeders().await?;
+pub async fn run_seeder_interactive(
+    active_env: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let root = utils::get_seeders().await?;
+    let names = utils::seeder_names(&root)?;
+    utils::warn_unprefixed(&names);
+    let seeders = environment::filter(&root, names, active_env);
 
     if seeders.is_empty() {
         println!("No seeders available in the seeders directory.");
         return Ok(());
     }
 
-    // Create tracker to check seeder status
-    let database_url = env::var("DATABASE_URL").map_err(|_| {
-        "Please, be sure to set the `DATABASE_URL` environment variable."
-    })?;
-
-    let tracker = SeederTracker::new(database_url)?;
-    tracker.ensure_seeds_table().await?;
+    // Each seeder is tracked in the database of its connection
+    let mut trackers = Trackers::new(utils::config::current());
 
     // Filter and annotate seeders with their execution status
     let mut annotated_seeders = Vec::new();
     for seeder in seeders {
-        let is_executed = tracker.is_seeder_executed(&seeder).await?;
+        let is_executed = trackers
+            .of_seeder(&root, &seeder)
+            .await?
+            .is_seeder_executed(&seeder)
+            .await?;
         if !is_executed {
             annotated_seeders.push(seeder.clone());
         }
@@ -152,8 +260,15 @@ pub async fn run_seeder_interactive() -> Result<(), Box<dyn Error>> {
         return Ok(());
     }
 
-    for seeder_name in selected {
-        match run_single_seeder_with_tracking(Some(&seeder_name)).await {
+    for seeder_name in requirements::order(&root, selected)? {
+        match run_single_seeder_with_tracking(
+            &mut trackers,
+            Some(&seeder_name),
+            active_env,
+            false,
+        )
+        .await
+        {
             Ok(_) => {}
             Err(e) => {
                 eprintln!(
@@ -168,42 +283,40 @@ pub async fn run_seeder_interactive() -> Result<(), Box<dyn Error>> {
 }
 
 fn read_seeder_timestamp(seeder_name: &str) -> i64 {
-    seeder_name
-        .split_once('_')
-        .and_then(|(ts, _)| ts.parse::<i64>().ok())
-        .unwrap_or_else(|| {
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs() as i64
-        })
+    utils::seeder_timestamp(seeder_name).unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    })
 }
 
+/// Runs a seeder and records it, after the pending seeders of its
+/// `#![requires(...)]`, which must run in `active_env` unless `force`.
+/// `trackers` keeps the tracker of each connection between seeders.
 async fn run_single_seeder_with_tracking(
+    trackers: &mut Trackers,
     file_name: Option<&String>,
+    active_env: Option<&str>,
+    force: bool,
 ) -> Result<(), Box<dyn Error>> {
-    let database_url = env::var("DATABASE_URL").map_err(|_| {
-        "Please, be sure to set the `DATABASE_URL` environment variable."
-    })?;
-
-    let tracker = SeederTracker::new(database_url.clone())?;
-    tracker.ensure_seeds_table().await?;
-
     // Extract seeder name from file path
     let seeder_name = if let Some(name) = file_name {
-        // Remove .ron extension if present
-        let name = if name.ends_with(".ron") {
-            &name[..name.len() - 4]
-        } else {
-            name
-        };
-        name.to_string()
+        // Remove the extension of the seeder format if present
+        seeder_format::seeder_name(name).to_string()
     } else {
         return Err("Seeder name is required for tracking".into());
     };
 
+    let root = utils::get_seeders().await?;
+
     // Check if seeder was already executed
-    if tracker.is_seeder_executed(&seeder_name).await? {
+    if trackers
+        .of_seeder(&root, &seeder_name)
+        .await?
+        .is_seeder_executed(&seeder_name)
+        .await?
+    {
         println!(
             "\x1b[1;33m[SKIP] {} has already been executed\x1b[0m",
             seeder_name
@@ -211,8 +324,38 @@ async fn run_single_seeder_with_tracking(
         return Ok(());
     }
 
+    let plan = requirements::plan(&root, &seeder_name)?;
+
+    for required in &plan[..plan.len() - 1] {
+        let tracker = trackers.of_seeder(&root, required).await?;
+        if tracker.is_seeder_executed(required).await? {
+            continue;
+        }
+
+        if !force {
+            environment::check(&root, required, active_env).map_err(|err| {
+                format!("{seeder_name} requires {required}: {err}")
+            })?;
+        }
+
+        println!(
+            "\x1b[1;33m[INFO] Running {required}, required by {seeder_name}\x1b[0m"
+        );
+        run_single_seeder(Some(required), tracker.database_url())
+            .await
+            .map_err(|err| {
+                format!(
+                    "{seeder_name} requires {required}, which failed: {err}"
+                )
+            })?;
+        tracker
+            .mark_seeder_executed(required, read_seeder_timestamp(required))
+            .await?;
+    }
+
     // Execute the seeder
-    run_single_seeder(file_name).await?;
+    let tracker = trackers.of_seeder(&root, &seeder_name).await?;
+    run_single_seeder(file_name, tracker.database_url()).await?;
 
     // Mark as executed using timestamp from file
     let timestamp = read_seeder_timestamp(&seeder_name);
@@ -223,89 +366,94 @@ async fn run_single_seeder_with_tracking(
     Ok(())
 }
 
+/// Runs a seeder in the database of `database_url`, the URL of its
+/// connection. The driver reads the statements while they are rendered.
 async fn run_single_seeder(
     file_name: Option<&String>,
+    database_url: &str,
 ) -> Result<(), Box<dyn Error>> {
-    let Ok(database_url) = env::var("DATABASE_URL") else {
-        return Err(
-            "Please, be sure to set the `DATABASE_URL` environment variable."
-                .into(),
-        );
-    };
+    let batch_size = utils::config::current().batch_size.value;
 
-    let entries = Entry::get_from_seeders(file_name).await?;
+    let seeder = Seeder::get_from_seeders(file_name).await?;
+    #[cfg(feature = "sqlx")]
+    let keep_sequences = seeder.kept_sequences();
 
-    let tables = template::render_tables(entries, &database_url).await?;
+    let scheme = SchemeDriver::from_str(database_url)?;
 
-    let scheme = SchemeDriver::from_str(&database_url)?;
+    // The renderer stays at most a batch ahead of the driver
+    let (sender, statements) = mpsc::channel(batch_size);
 
-    match scheme {
-        SchemeDriver::Mock => {
-            for (table, rows) in tables {
-                for fields in rows {
-                    let (columns, values) =
-                        fields.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+    let run = async {
+        let database_url = database_url.to_owned();
 
-                    let query = format!(
-                        "INSERT INTO {} ({}) VALUES ({})",
-                        table,
-                        columns.join(", "),
-                        values
-                            .iter()
-                            .map(|v| v.to_string())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    );
+        match scheme {
+            SchemeDriver::Mock => print_statements(statements).await,
 
-                    println!("{query}");
-                }
+            #[cfg(feature = "libsql")]
+            SchemeDriver::Libsql => {
+                grow_libsql::run_statements(
+                    database_url,
+                    statements,
+                    batch_size,
+                )
+                .await
+            }
+            #[cfg(feature = "sqlx")]
+            SchemeDriver::Sqlx => {
+                grow_sqlx::run_statements(
+                    database_url,
+                    statements,
+                    &keep_sequences,
+                    batch_size,
+                )
+                .await
+            }
+            #[cfg(feature = "surrealdb")]
+            SchemeDriver::Surrealdb => {
+                grow_surrealdb::run_statements_with_connection_string(
+                    &database_url,
+                    statements,
+                )
+                .await
             }
         }
+    };
 
-        #[cfg(feature = "libsql")]
-        SchemeDriver::Libsql => {
-            let converted_tables = convert_tables_for_libsql(tables);
-            grow_libsql::run_seeder(database_url, converted_tables).await?
-        }
-        #[cfg(feature = "sqlx")]
-        SchemeDriver::Sqlx => {
-            let converted_tables = convert_tables_for_sqlx(tables);
-            grow_sqlx::run_seeder(database_url, converted_tables).await?
-        }
-        #[cfg(feature = "surrealdb")]
-        SchemeDriver::Surrealdb => {
-            let converted_tables = convert_tables_for_surrealdb(tables);
-            grow_surrealdb::run_seeder_with_connection_string(
-                &database_url,
-                &converted_tables,
-            )
-            .await?
-        }
-    }
+    let ((), result) = futures::join!(
+        template::send_statements(seeder, database_url, sender),
+        run
+    );
 
-    Ok(())
+    Ok(result?)
 }
 
-#[cfg(feature = "libsql")]
-fn convert_tables_for_libsql(
-    tables: BTreeMap<String, template::RenderedTable>,
-) -> BTreeMap<String, Vec<Vec<(String, SqlValue)>>> {
-    // No need for conversion since both use the same SqlValue from grow_core
-    tables
-}
+/// Prints the statements of the mock driver as SQL, failing on the first
+/// error as a driver would
+async fn print_statements(
+    mut statements: template::Statements,
+) -> Result<(), String> {
+    while let Some(statement) = statements.next().await {
+        match statement? {
+            Statement::Insert { table, row } => {
+                let (columns, values) =
+                    row.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+
+                let query = format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    table,
+                    columns.join(", "),
+                    values
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
 
-#[cfg(feature = "sqlx")]
-fn convert_tables_for_sqlx(
-    tables: BTreeMap<String, template::RenderedTable>,
-) -> BTreeMap<String, Vec<Vec<(String, SqlValue)>>> {
-    // No need for conversion since both use the same SqlValue from grow_core
-    tables
-}
+                println!("{query}");
+            }
+            Statement::Sql(sql) => println!("{sql}"),
+        }
+    }
 
-#[cfg(feature = "surrealdb")]
-fn convert_tables_for_surrealdb(
-    tables: BTreeMap<String, template::RenderedTable>,
-) -> BTreeMap<String, Vec<Vec<(String, SqlValue)>>> {
-    // No need for conversion since both use the same SqlValue from grow_core
-    tables
+    Ok(())
 }
@@ -0,0 +1,84 @@
+use std::fmt::Write;
+
+use chrono::NaiveDateTime;
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+
+use super::datetime::{self, DATE_FORMAT, TIMESTAMP_FORMAT};
+
+/// `now()`: current timestamp
+pub fn now(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 0)?;
+
+    Ok(datetime::now().format(TIMESTAMP_FORMAT).to_string())
+}
+
+/// `today()`: current date
+pub fn today(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 0)?;
+
+    Ok(datetime::now().format(DATE_FORMAT).to_string())
+}
+
+/// `now_offset(duration)`: current timestamp moved by a signed duration,
+/// e.g. `now_offset(-7d)`
+pub fn now_offset(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 1)?;
+
+    let duration = datetime::parse_duration(&args[0])
+        .map_err(function::Error::InvalidArgument)?;
+
+    add(datetime::now(), duration)
+}
+
+/// `timestamp_add(timestamp, amount, unit)`: moves a timestamp by `amount`
+/// units, e.g. `timestamp_add(now(), i, "minutes")`
+pub fn timestamp_add(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 3)?;
+    validations::args_max_len(args, 3)?;
+
+    let timestamp = parse_timestamp(&args[0])?;
+    let amount = args[1].trim();
+    if amount.parse::<i64>().is_err() {
+        return Err(function::Error::InvalidType(args[1].to_owned()));
+    }
+
+    let duration =
+        datetime::parse_duration(&format!("{amount}{}", args[2].trim()))
+            .map_err(function::Error::InvalidArgument)?;
+
+    add(timestamp, duration)
+}
+
+/// `format_date(timestamp, format)`: formats a date or timestamp with
+/// [chrono's format](https://docs.rs/chrono/latest/chrono/format/strftime)
+pub fn format_date(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 2)?;
+    validations::args_max_len(args, 2)?;
+
+    let timestamp = parse_timestamp(&args[0])?;
+
+    let mut formatted = String::new();
+    write!(formatted, "{}", timestamp.format(&args[1])).map_err(|_| {
+        function::Error::InvalidArgument(format!(
+            "Invalid date format: {}",
+            args[1]
+        ))
+    })?;
+
+    Ok(formatted)
+}
+
+fn add(timestamp: NaiveDateTime, duration: chrono::Duration) -> FuncResult {
+    timestamp
+        .checked_add_signed(duration)
+        .map(|timestamp| timestamp.format(TIMESTAMP_FORMAT).to_string())
+        .ok_or_else(|| {
+            function::Error::RuntimeError("Timestamp out of range".to_owned())
+        })
+}
+
+fn parse_timestamp(arg: &str) -> Result<NaiveDateTime, function::Error> {
+    datetime::parse_timestamp(arg).map_err(function::Error::InvalidArgument)
+}
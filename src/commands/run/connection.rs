@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::Path;
+
+use super::entry::Seeder;
+use super::seeder_tracker::SeederTracker;
+use crate::utils::config::{Config, DEFAULT_CONNECTION};
+
+/// Connection of the `#![connection = "..."]` of the seeder `name`, the
+/// default connection when it has none
+pub fn seeder_connection(root: &Path, name: &str) -> Result<String, String> {
+    Ok(Seeder::read(root, name)?
+        .connection
+        .unwrap_or_else(|| DEFAULT_CONNECTION.to_owned()))
+}
+
+/// Seeders of `names` grouped by connection, the default connection first
+/// and the others in the order they appear, keeping the order of the
+/// seeders. Seeders that cannot be read go to the default connection, so
+/// running them reports the error.
+pub fn group(root: &Path, names: Vec<String>) -> Vec<(String, Vec<String>)> {
+    let mut groups = vec![(DEFAULT_CONNECTION.to_owned(), vec![])];
+
+    for name in names {
+        let connection = seeder_connection(root, &name)
+            .unwrap_or_else(|_| DEFAULT_CONNECTION.to_owned());
+
+        match groups.iter_mut().find(|(group, _)| *group == connection) {
+            Some((_, seeders)) => seeders.push(name),
+            None => groups.push((connection, vec![name])),
+        }
+    }
+
+    groups.retain(|(_, seeders)| !seeders.is_empty());
+    groups
+}
+
+/// Trackers of the connections, each one with the tracking table of its
+/// database, which is created when the connection is first used
+pub struct Trackers {
+    config: Config,
+    trackers: BTreeMap<String, SeederTracker>,
+}
+
+impl Trackers {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            trackers: BTreeMap::new(),
+        }
+    }
+
+    pub async fn get(
+        &mut self,
+        connection: &str,
+    ) -> Result<&SeederTracker, Box<dyn Error>> {
+        if !self.trackers.contains_key(connection) {
+            let tracker = SeederTracker::new(
+                self.config.connection_url(connection)?,
+                self.config.tracking_table.value.clone(),
+            )?;
+            tracker.ensure_seeds_table().await?;
+            self.trackers.insert(connection.to_owned(), tracker);
+        }
+
+        Ok(&self.trackers[connection])
+    }
+
+    /// Tracker of the connection of the seeder `name`, seeders that cannot
+    /// be read use the default connection
+    pub async fn of_seeder(
+        &mut self,
+        root: &Path,
+        name: &str,
+    ) -> Result<&SeederTracker, Box<dyn Error>> {
+        let connection = seeder_connection(root, name)
+            .unwrap_or_else(|_| DEFAULT_CONNECTION.to_owned());
+
+        self.get(&connection).await
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use grow_core::SqlValue;
+
+pub type Row = BTreeMap<String, SqlValue>;
+
+/// Rows read one by one, so large files are not kept in memory
+pub type Rows = Box<dyn Iterator<Item = Result<Row, String>> + Send>;
+
+/// File whose rows are the values of an entry, as `#[csv = "..."]` or
+/// `#[json = "..."]`
+#[derive(Debug, Clone)]
+pub enum DataFile {
+    Csv(PathBuf),
+    Json(PathBuf),
+}
+
+impl DataFile {
+    /// Name of the attribute that reads this kind of file
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            DataFile::Csv(_) => "csv",
+            DataFile::Json(_) => "json",
+        }
+    }
+
+    /// Makes a relative path relative to the seeders folder
+    pub fn resolve(&mut self, seeders: &Path) {
+        let (DataFile::Csv(path) | DataFile::Json(path)) = self;
+
+        if path.is_relative() {
+            *path = seeders.join(&*path);
+        }
+    }
+
+    pub fn read_rows(&self) -> Result<Rows, String> {
+        match self {
+            DataFile::Csv(path) => read_csv(path),
+            DataFile::Json(path) if is_json_lines(path) => {
+                read_json_lines(path)
+            }
+            DataFile::Json(path) => read_json(path),
+        }
+    }
+}
+
+/// Rows of a CSV file, the headers are the columns
+fn read_csv(path: &Path) -> Result<Rows, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_path(path)
+        .map_err(|err| format!("{path:#?}: {err}"))?;
+
+    let headers = reader
+        .headers()
+        .map_err(|err| format!("{path:#?}: {err}"))?
+        .clone();
+    let path = path.to_owned();
+
+    Ok(Box::new(reader.into_records().map(move |record| {
+        let record = record.map_err(|err| format!("{path:#?}: {err}"))?;
+
+        Ok(headers
+            .iter()
+            .zip(record.iter())
+            .map(|(header, cell)| (header.to_owned(), cell_value(cell)))
+            .collect())
+    })))
+}
+
+/// `.ndjson` and `.jsonl` files have one object per line
+fn is_json_lines(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "ndjson" || extension == "jsonl")
+}
+
+/// Rows of a JSON array of objects, the whole array is read at once
+fn read_json(path: &Path) -> Result<Rows, String> {
+    let file = File::open(path).map_err(|err| format!("{path:#?}: {err}"))?;
+    let objects: Vec<serde_json::Value> =
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|err| format!("{path:#?}: {err}"))?;
+    let path = path.to_owned();
+
+    Ok(Box::new(objects.into_iter().enumerate().map(
+        move |(index, object)| {
+            json_row(object).map_err(|err| format!("{path:#?}[{index}]: {err}"))
+        },
+    )))
+}
+
+/// Rows of a NDJSON file, read line by line skipping the empty ones
+fn read_json_lines(path: &Path) -> Result<Rows, String> {
+    let file = File::open(path).map_err(|err| format!("{path:#?}: {err}"))?;
+    let path = path.to_owned();
+
+    let rows = BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            line.as_ref().map_or(true, |line| !line.trim().is_empty())
+        })
+        .map(move |(index, line)| {
+            line.map_err(|err| err.to_string())
+                .and_then(|line| {
+                    serde_json::from_str(&line).map_err(|err| err.to_string())
+                })
+                .and_then(json_row)
+                .map_err(|err| format!("{path:#?}:{}: {err}", index + 1))
+        });
+
+    Ok(Box::new(rows))
+}
+
+/// Row of a JSON object, its keys are the columns
+fn json_row(object: serde_json::Value) -> Result<Row, String> {
+    match object {
+        serde_json::Value::Object(fields) => Ok(fields
+            .into_iter()
+            .map(|(key, value)| (key, json_value(value)))
+            .collect()),
+        other => Err(format!("Expected an object, found `{other}`")),
+    }
+}
+
+/// Value of a JSON field. Nested objects and arrays are kept as JSON
+/// documents for JSON columns.
+pub fn json_value(value: serde_json::Value) -> SqlValue {
+    match value {
+        serde_json::Value::Null => SqlValue::Null,
+        serde_json::Value::Bool(value) => SqlValue::Boolean(value),
+        serde_json::Value::Number(number) => match number.as_i64() {
+            Some(value) => SqlValue::Integer(value),
+            None => number
+                .as_f64()
+                .map(SqlValue::Float)
+                .unwrap_or_else(|| SqlValue::Text(number.to_string())),
+        },
+        serde_json::Value::String(text) => SqlValue::Text(text),
+        document @ (serde_json::Value::Array(_)
+        | serde_json::Value::Object(_)) => SqlValue::Json(document.to_string()),
+    }
+}
+
+/// Value of a cell. Empty cells are NULL, numbers and booleans keep their
+/// type and numbers with leading zeros such as `007` stay text.
+pub fn cell_value(cell: &str) -> SqlValue {
+    let digits = cell.strip_prefix('-').unwrap_or(cell);
+    let leading_zero = digits.len() > 1
+        && digits.starts_with('0')
+        && !digits.starts_with("0.");
+
+    match cell {
+        "" => SqlValue::Null,
+        "true" => SqlValue::Boolean(true),
+        "false" => SqlValue::Boolean(false),
+        _ if leading_zero
+            || !digits.starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+            SqlValue::Text(cell.to_owned())
+        }
+        _ => {
+            if let Ok(value) = cell.parse::<i64>() {
+                SqlValue::Integer(value)
+            } else if let Ok(value) = cell.parse::<f64>() {
+                SqlValue::Float(value)
+            } else {
+                SqlValue::Text(cell.to_owned())
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::RwLock;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+
+/// Format of dates rendered by template functions
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Format of timestamps rendered by template functions
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Time returned by [`now`] instead of the system clock, set by `--now`
+static FROZEN_CLOCK: RwLock<Option<NaiveDateTime>> = RwLock::new(None);
+
+/// Freezes the clock used by template functions, `None` restores the
+/// system clock
+pub fn freeze_clock(now: Option<NaiveDateTime>) {
+    if let Ok(mut clock) = FROZEN_CLOCK.write() {
+        *clock = now;
+    }
+}
+
+/// Current UTC time, or the frozen time when the clock was frozen
+pub fn now() -> NaiveDateTime {
+    FROZEN_CLOCK
+        .read()
+        .ok()
+        .and_then(|clock| *clock)
+        .unwrap_or_else(|| Utc::now().naive_utc())
+}
+
+/// Parses a signed duration such as `30d`, `-7d`, `12h`, `15m`, `90s` or `2w`.
+/// A number without unit is read as days.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let unit_start = text
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    let (amount, unit) = text.split_at(unit_start);
+
+    let Ok(amount) = amount.parse::<i64>() else {
+        return Err(format!("Invalid duration: {text}"));
+    };
+
+    let duration = match unit {
+        "s" | "sec" | "secs" | "seconds" => Duration::try_seconds(amount),
+        "m" | "min" | "mins" | "minutes" => Duration::try_minutes(amount),
+        "h" | "hour" | "hours" => Duration::try_hours(amount),
+        "" | "d" | "day" | "days" => Duration::try_days(amount),
+        "w" | "week" | "weeks" => Duration::try_weeks(amount),
+        _ => return Err(format!("Unknown duration unit `{unit}` in {text}")),
+    };
+
+    duration.ok_or_else(|| format!("Duration out of range: {text}"))
+}
+
+/// Parses a `YYYY-MM-DD` date, a `YYYY-MM-DD HH:MM:SS` timestamp or an
+/// RFC 3339 datetime. Dates are read as midnight.
+pub fn parse_timestamp(text: &str) -> Result<NaiveDateTime, String> {
+    let text = text.trim();
+
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Ok(datetime.naive_utc());
+    }
+
+    for format in [TIMESTAMP_FORMAT, "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(text, format) {
+            return Ok(datetime);
+        }
+    }
+
+    NaiveDate::parse_from_str(text, DATE_FORMAT)
+        .map(|date| date.and_time(Default::default()))
+        .map_err(|_| format!("Invalid date: {text}"))
+}
@@ -1,9 +1,13 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use ron_next::*;
 
+use super::data_file::DataFile;
+use super::seeder_format::{self, SeederFormat};
+use super::variables;
 use crate::utils;
 use grow_core::SqlValue;
 
@@ -13,70 +17,408 @@ pub enum Entry {
         count: usize,
         table_name: String,
         fields: BTreeMap<String, SqlValue>,
+        options: EntryOptions,
     },
     Static {
         table_name: String,
         values: Vec<BTreeMap<String, SqlValue>>,
+        options: EntryOptions,
     },
+    /// Rows read from a file such as `#[csv = "data/countries.csv"]` or
+    /// `#[json = "fixtures/users.ndjson"]`, the values are only rendered as
+    /// templates with `#[render = true]`
+    File {
+        table_name: String,
+        source: DataFile,
+        render: bool,
+        options: EntryOptions,
+    },
+    /// Raw SQL run in source order, as `#[sql] _: "UPDATE ..."`, the hooks
+    /// of `#![before(...)]`/`#![after(...)]` or a statement of a `.sql` seeder
+    Sql { statement: String },
 }
 
-impl Entry {
-    pub async fn get_from_seeders(
-        file_name: Option<&String>,
-    ) -> Result<Vec<Entry>, String> {
-        let mut seeders_path = utils::get_seeders().await?;
+/// Inline attributes that change how the rows of an entry are rendered
+#[derive(Debug, Clone, Default)]
+pub struct EntryOptions {
+    /// Fields of `#[unique = [...]]`, regenerated when their value repeats
+    pub unique: Vec<String>,
+    /// Locale of `#[locale = "..."]` for plain `fake` kinds
+    pub locale: Option<String>,
+    /// `#[reset_sequence = false]` keeps the sequences of the table as they
+    /// are after inserting explicit IDs
+    pub keep_sequence: bool,
+    /// Row template of `#[template = "..."]`, whose fields the rows override
+    pub template: Option<String>,
+}
 
-        if let Some(file_name) = file_name {
-            let file_name_with_extension = if file_name.ends_with(".ron") {
-                file_name.clone()
-            } else {
-                format!("{}.ron", file_name)
+/// Content of one or more seeder files
+#[derive(Debug, Default)]
+pub struct Seeder {
+    /// Variables of `#![vars(...)]`, in declaration order
+    pub vars: Vec<(String, String)>,
+    pub entries: Vec<Entry>,
+    /// Seeders of `#![include(...)]`, relative to the seeders folder
+    pub includes: Vec<String>,
+    /// Row templates of `#![templates(...)]` by name
+    pub templates: BTreeMap<String, BTreeMap<String, SqlValue>>,
+    /// Environments of `#![env(...)]`, empty when the seeder runs in all
+    pub environments: Vec<String>,
+    /// Seeders of `#![requires(...)]`, which must run before this one
+    pub requires: Vec<String>,
+    /// Connection of `#![connection = "..."]` of grow.toml, the default
+    /// database when `None`
+    pub connection: Option<String>,
+}
+
+impl From<Vec<Entry>> for Seeder {
+    fn from(entries: Vec<Entry>) -> Self {
+        Seeder {
+            entries,
+            ..Default::default()
+        }
+    }
+}
+
+impl Seeder {
+    /// Tables whose sequences are kept, as `#[reset_sequence = false]`
+    pub fn kept_sequences(&self) -> BTreeSet<String> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Repeat {
+                    table_name,
+                    options,
+                    ..
+                }
+                | Entry::Static {
+                    table_name,
+                    options,
+                    ..
+                }
+                | Entry::File {
+                    table_name,
+                    options,
+                    ..
+                } if options.keep_sequence => Some(table_name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Merges the row templates into the fields of the entries that use
+    /// them, the fields of an entry take precedence
+    pub fn apply_templates(mut self) -> Result<Self, String> {
+        for entry in &mut self.entries {
+            let (table_name, rows, options) = match entry {
+                Entry::Repeat {
+                    table_name,
+                    fields,
+                    options,
+                    ..
+                } => (table_name, std::slice::from_mut(fields), options),
+                Entry::Static {
+                    table_name,
+                    values,
+                    options,
+                } => (table_name, values.as_mut_slice(), options),
+                _ => continue,
+            };
+
+            let Some(name) = &options.template else {
+                continue;
+            };
+            let Some(template) = self.templates.get(name) else {
+                return Err(format!(
+                    "Unknown template `{name}` in {table_name}"
+                ));
             };
 
-            seeders_path.push(file_name_with_extension);
+            for row in rows {
+                let fields = std::mem::take(row);
+                *row = template.clone();
+                row.extend(fields);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Adds the variables, entries and templates of `other` after its own
+    fn append(&mut self, other: &mut Seeder) {
+        self.vars.append(&mut other.vars);
+        self.entries.append(&mut other.entries);
+        self.templates.append(&mut other.templates);
+    }
+
+    pub async fn get_from_seeders(
+        file_name: Option<&String>,
+    ) -> Result<Seeder, String> {
+        let seeders_path = utils::get_seeders().await?;
 
-            Self::get_from_file(seeders_path)
+        Self::get_from_root(&seeders_path, file_name)
+    }
+
+    /// Seeder of `file_name` in the `root` folder, or of all the seeders
+    /// in it but the partials
+    pub fn get_from_root(
+        root: &Path,
+        file_name: Option<&String>,
+    ) -> Result<Seeder, String> {
+        let seeder = if let Some(file_name) = file_name {
+            Self::get_from_file(
+                seeder_format::seeder_path(root, file_name),
+                root,
+                &mut vec![],
+                &mut BTreeSet::new(),
+            )
         } else {
-            Self::get_from_folder(seeders_path)
+            Self::get_from_folder(root)
+        }?;
+
+        Ok(seeder.resolve_data_files(root))
+    }
+
+    /// Makes the paths of the data files relative to the seeders folder
+    fn resolve_data_files(mut self, root: &Path) -> Self {
+        for entry in &mut self.entries {
+            if let Entry::File { source, .. } = entry {
+                source.resolve(root);
+            }
         }
+
+        self
     }
 
-    fn get_from_folder(path: PathBuf) -> Result<Vec<Entry>, String> {
-        let mut entries = vec![];
+    fn get_from_folder(root: &Path) -> Result<Seeder, String> {
+        let mut seeder = Seeder::default();
 
-        let seeder_entries = path
-            .read_dir()
-            .map_err(utils::map_io_error(&path))?
-            .filter_map(|entry| entry.ok().map(|e| e.path()))
-            .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
-            .map(Self::get_from_file);
+        let seeder_files = utils::seeder_names(root)?
+            .into_iter()
+            .map(|name| seeder_format::seeder_path(root, &name))
+            .map(|path| {
+                Self::get_from_file(
+                    path,
+                    root,
+                    &mut vec![],
+                    &mut BTreeSet::new(),
+                )
+            });
 
-        for entry in seeder_entries {
-            entries.append(&mut entry?)
+        for file in seeder_files {
+            seeder.append(&mut file?);
         }
 
-        Ok(entries)
+        Ok(seeder)
     }
 
-    fn get_from_file(path: PathBuf) -> Result<Vec<Entry>, String> {
+    /// Seeder `name` of the `root` folder without its includes, to read the
+    /// attributes of the file
+    pub fn read(root: &Path, name: &str) -> Result<Seeder, String> {
+        let path = seeder_format::seeder_path(root, name);
         let content =
             fs::read_to_string(&path).map_err(utils::map_io_error(&path))?;
+        let format =
+            SeederFormat::from_path(&path).unwrap_or(SeederFormat::Ron);
+
+        format
+            .parse(content)
+            .map_err(|err| format!("{err} in {path:#?}"))
+    }
+
+    /// Seeder of a file with its includes first, `including` holds the
+    /// files being loaded to find include cycles and `included` the files
+    /// already loaded, which are included once
+    fn get_from_file(
+        path: PathBuf,
+        root: &Path,
+        including: &mut Vec<PathBuf>,
+        included: &mut BTreeSet<PathBuf>,
+    ) -> Result<Seeder, String> {
+        let key = path.canonicalize().unwrap_or_else(|_| path.clone());
+        let relative = |file: &PathBuf| {
+            file.strip_prefix(root.canonicalize().unwrap_or_default())
+                .unwrap_or(file)
+                .display()
+                .to_string()
+        };
+
+        if let Some(start) = including.iter().position(|file| *file == key) {
+            let chain = including[start..]
+                .iter()
+                .chain([&key])
+                .map(relative)
+                .collect::<Vec<_>>();
+
+            return Err(format!("Include cycle: {}", chain.join(" -> ")));
+        }
+
+        if !included.insert(key.clone()) {
+            return Ok(Seeder::default());
+        }
+
+        let content =
+            fs::read_to_string(&path).map_err(utils::map_io_error(&path))?;
+        let format =
+            SeederFormat::from_path(&path).unwrap_or(SeederFormat::Ron);
+
+        let mut file = format
+            .parse(content)
+            .map_err(|err| format!("{err} in {path:#?}"))?;
+
+        // Only the seeder being run decides where and after what it runs
+        if !including.is_empty()
+            && (!file.environments.is_empty()
+                || !file.requires.is_empty()
+                || file.connection.is_some())
+        {
+            return Err(format!(
+                "The included seeder {} cannot set env, requires or \
+                connection, set them on the seeder including it",
+                relative(&key)
+            ));
+        }
+
+        if file.includes.is_empty() {
+            return Ok(file);
+        }
+
+        including.push(key);
 
-        let content = File::try_from(content.as_str())
-            .map_err(|err| format!("Cannot parse {path:#?}: {err}"))?;
+        let mut seeder = Seeder::default();
 
-        match content.value.content {
+        for include in file.includes.drain(..) {
+            let mut included = Self::get_from_file(
+                seeder_format::seeder_path(root, &include),
+                root,
+                including,
+                included,
+            )?;
+            seeder.append(&mut included);
+        }
+
+        including.pop();
+
+        // The variables and templates of the file override the included ones
+        seeder.append(&mut file);
+
+        Ok(seeder)
+    }
+
+    /// Parses the content of a seeder file
+    pub fn parse(mut content: String) -> Result<Seeder, String> {
+        let vars = match take_file_attribute(&mut content, "vars")? {
+            Some(arguments) => parse_vars(&arguments)?,
+            None => vec![],
+        };
+        let includes = match take_file_attribute(&mut content, "include")? {
+            Some(arguments) => {
+                parse_strings(&arguments, "include", "seeder paths")?
+            }
+            None => vec![],
+        };
+        let environments = match take_file_attribute(&mut content, "env")? {
+            Some(arguments) => {
+                parse_strings(&arguments, "env", "environment names")?
+            }
+            None => vec![],
+        };
+        let requires = match take_file_attribute(&mut content, "requires")? {
+            Some(arguments) => {
+                parse_strings(&arguments, "requires", "seeder names")?
+            }
+            None => vec![],
+        };
+        let connection = match take_file_attribute(&mut content, "connection")?
+        {
+            Some(arguments) => {
+                match parse_strings(
+                    &arguments,
+                    "connection",
+                    "connection name",
+                )?
+                .as_slice()
+                {
+                    [name] => Some(name.clone()),
+                    _ => {
+                        return Err("Expect one connection name in \
+                            #![connection = \"...\"]"
+                            .to_owned())
+                    }
+                }
+            }
+            None => None,
+        };
+        let templates = match take_file_attribute(&mut content, "templates")? {
+            Some(arguments) => parse_templates(&arguments)?,
+            None => BTreeMap::new(),
+        };
+        let before = match take_file_attribute(&mut content, "before")? {
+            Some(arguments) => parse_hooks(&arguments, "before")?,
+            None => vec![],
+        };
+        let after = match take_file_attribute(&mut content, "after")? {
+            Some(arguments) => parse_hooks(&arguments, "after")?,
+            None => vec![],
+        };
+
+        let file = File::try_from(content.as_str())
+            .map_err(|err| format!("Cannot parse seeder: {err}"))?;
+
+        let entries = match file.value.content {
             Value::Map(map) => map
                 .0
                 .values
                 .into_iter()
                 .map(|i| i.content)
                 .map(Entry::try_from)
-                .collect::<Result<Vec<Entry>, String>>()
-                .map_err(|err| format!("{err} in {path:#?}")),
+                .collect::<Result<Vec<Entry>, String>>()?,
+
+            _ => return Err("Expect map".to_owned()),
+        };
+
+        // Hooks run around the entries in the same transaction
+        let entries = before.into_iter().chain(entries).chain(after).collect();
+
+        Ok(Seeder {
+            vars,
+            entries,
+            includes,
+            templates,
+            environments,
+            requires,
+            connection,
+        })
+    }
+}
 
-            _ => Err(format!("Expect map in {path:#?}")),
+impl Entry {
+    /// Entry whose rows are read from `source`, which cannot be repeated
+    pub(super) fn from_file(
+        table_name: String,
+        source: DataFile,
+        repeat: Option<usize>,
+        render: bool,
+        options: EntryOptions,
+    ) -> Result<Entry, String> {
+        if repeat.is_some() || options.template.is_some() {
+            let other = if repeat.is_some() {
+                "repeat"
+            } else {
+                "template"
+            };
+            return Err(format!(
+                "#[{}] cannot be used with #[{other}] in {table_name}",
+                source.attribute()
+            ));
         }
+
+        Ok(Entry::File {
+            table_name,
+            source,
+            render,
+            options,
+        })
     }
 }
 
@@ -89,6 +431,8 @@ impl TryFrom<MapItem<'_>> for Entry {
             Option<usize>,
             Option<String>,
         ) = (None, None);
+        let (mut data_file, mut render, mut sql) = (None, false, false);
+        let mut options = EntryOptions::default();
 
         if let Some(ref attributes) = map_item.attributes {
             // Convert Vec<WsLead<InlineAttribute>> to slice of InlineAttribute
@@ -96,6 +440,20 @@ impl TryFrom<MapItem<'_>> for Entry {
                 attributes.iter().map(|w| &w.content).collect();
             repeat_count = extract_repeat_count(&attrs);
             schema_name = extract_schema_name(&attrs);
+            options.unique = extract_unique_columns(&attrs)?;
+            options.locale = extract_locale(&attrs);
+            options.keep_sequence = extract_keep_sequence(&attrs);
+            options.template = extract_template(&attrs);
+            data_file = extract_data_file(&attrs)?;
+            render = extract_render(&attrs);
+            sql = extract_flag(&attrs, "sql");
+        }
+
+        if sql {
+            return match sql_value_from_value(map_item.value.content) {
+                Ok(SqlValue::Text(statement)) => Ok(Entry::Sql { statement }),
+                _ => Err("Expect string as value of #[sql]".to_owned()),
+            };
         }
 
         // Then process the key (fallback to old syntax if no attributes)
@@ -173,16 +531,17 @@ impl TryFrom<MapItem<'_>> for Entry {
             }
         };
 
-        // Apply schema from inline attributes if present
-        let final_table_name = if let Some(schema) = schema_name {
-            if table_name.contains('.') {
-                table_name.to_owned() // Keep existing schema if table already has one
-            } else {
-                format!("{}.{}", schema, table_name) // Apply schema from attribute
-            }
-        } else {
-            normalize_table_name(table_name)
-        };
+        let final_table_name = table_name_in_schema(table_name, schema_name);
+
+        if let Some(source) = data_file {
+            return Entry::from_file(
+                final_table_name,
+                source,
+                repeated,
+                render,
+                options,
+            );
+        }
 
         if let Some(count) = repeated {
             let (_, fields) =
@@ -192,6 +551,7 @@ impl TryFrom<MapItem<'_>> for Entry {
                 count,
                 table_name: final_table_name,
                 fields,
+                options,
             })
         } else {
             let values = match map_item.value.content {
@@ -213,11 +573,218 @@ impl TryFrom<MapItem<'_>> for Entry {
             Ok(Entry::Static {
                 table_name: final_table_name,
                 values,
+                options,
             })
         }
     }
 }
 
+/// Removes the `#![name(...)]` attribute from the header of a seeder and
+/// returns its arguments. ron-next only understands `#![enable(...)]`, so the
+/// attribute is blanked out before parsing, which keeps line numbers intact.
+fn take_file_attribute(
+    content: &mut String,
+    name: &str,
+) -> Result<Option<String>, String> {
+    let mut position = 0;
+
+    loop {
+        let rest = &content[position..];
+        let trimmed = rest.trim_start();
+        position += rest.len() - trimmed.len();
+
+        if trimmed.starts_with("//") {
+            position += trimmed.find('\n').unwrap_or(trimmed.len());
+        } else if trimmed.starts_with("/*") {
+            position += trimmed.find("*/").map_or(trimmed.len(), |end| end + 2);
+        } else if trimmed.starts_with("#![") {
+            let end = position + attribute_end(trimmed)?;
+            let attribute = content[position + 3..end - 1].trim();
+
+            let Some(arguments) = attribute.strip_prefix(name).filter(|rest| {
+                !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_')
+            }) else {
+                position = end;
+                continue;
+            };
+
+            // `#![name = value]` is the same as `#![name(value)]`
+            let arguments = arguments.trim();
+            let Some(arguments) = arguments.strip_prefix('=').or_else(|| {
+                arguments
+                    .strip_prefix('(')
+                    .and_then(|arguments| arguments.strip_suffix(')'))
+            }) else {
+                return Err(format!("Expected #![{name}(...)]"));
+            };
+
+            let arguments = arguments.to_owned();
+            let blank: String = content[position..end]
+                .chars()
+                .map(|c| if c == '\n' { c } else { ' ' })
+                .collect();
+            content.replace_range(position..end, &blank);
+
+            return Ok(Some(arguments));
+        } else {
+            return Ok(None);
+        }
+    }
+}
+
+/// Length of the `#![...]` attribute at the start of `text`
+fn attribute_end(text: &str) -> Result<usize, String> {
+    let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Ok(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err("Unclosed file attribute".to_owned())
+}
+
+/// Parses the `name: value` pairs of `#![vars(...)]`
+fn parse_vars(arguments: &str) -> Result<Vec<(String, String)>, String> {
+    if arguments.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let arguments = format!("({arguments})");
+    let file = File::try_from(arguments.as_str())
+        .map_err(|err| format!("Cannot parse #![vars(...)]: {err}"))?;
+
+    let Value::Struct(Struct {
+        ident: None,
+        fields,
+    }) = file.value.content
+    else {
+        return Err("Expect `name: value` pairs in #![vars(...)]".to_owned());
+    };
+
+    fields
+        .values
+        .into_iter()
+        .map(|field| {
+            let name = field.content.key.to_owned();
+            variables::validate_name(&name)?;
+
+            let value = sql_value_from_value(field.content.value.content)
+                .map_err(|err| format!("{err} in variable `{name}`"))?;
+
+            Ok((name, value.to_string()))
+        })
+        .collect()
+}
+
+/// Parses the rows of `#![templates(name: (...), ...)]`
+fn parse_templates(
+    arguments: &str,
+) -> Result<BTreeMap<String, BTreeMap<String, SqlValue>>, String> {
+    if arguments.trim().is_empty() {
+        return Ok(BTreeMap::new());
+    }
+
+    let arguments = format!("({arguments})");
+    let file = File::try_from(arguments.as_str())
+        .map_err(|err| format!("Cannot parse #![templates(...)]: {err}"))?;
+
+    let Value::Struct(Struct {
+        ident: None,
+        fields,
+    }) = file.value.content
+    else {
+        return Err(
+            "Expect `name: (...)` pairs in #![templates(...)]".to_owned()
+        );
+    };
+
+    fields
+        .values
+        .into_iter()
+        .map(|field| {
+            let name = field.content.key.to_owned();
+            let (_, fields) =
+                fields_from_value(field.content.value.content, &name)
+                    .map_err(|err| format!("{err} in template `{name}`"))?;
+
+            Ok((name, fields))
+        })
+        .collect()
+}
+
+/// Applies the schema of `#[schema = "..."]`, unless the table already has one
+pub(super) fn table_name_in_schema(
+    table_name: &str,
+    schema: Option<String>,
+) -> String {
+    match schema {
+        Some(_) if table_name.contains('.') => table_name.to_owned(),
+        Some(schema) => format!("{schema}.{table_name}"),
+        None => normalize_table_name(table_name),
+    }
+}
+
+/// Parses the SQL statements of `#![before(...)]` and `#![after(...)]`
+fn parse_hooks(arguments: &str, name: &str) -> Result<Vec<Entry>, String> {
+    Ok(parse_strings(arguments, name, "SQL strings")?
+        .into_iter()
+        .map(|statement| Entry::Sql { statement })
+        .collect())
+}
+
+/// Strings of a file attribute as `#![include("a.ron", "b.ron")]`
+fn parse_strings(
+    arguments: &str,
+    name: &str,
+    expected: &str,
+) -> Result<Vec<String>, String> {
+    if arguments.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    // The trailing comma keeps a single string a tuple
+    let arguments = format!("({},)", arguments.trim().trim_end_matches(','));
+    let file = File::try_from(arguments.as_str())
+        .map_err(|err| format!("Cannot parse #![{name}(...)]: {err}"))?;
+
+    let Value::Tuple(Tuple {
+        ident: None,
+        fields,
+    }) = file.value.content
+    else {
+        return Err(format!("Expect {expected} in #![{name}(...)]"));
+    };
+
+    fields
+        .values
+        .into_iter()
+        .map(|field| match sql_value_from_value(field.content) {
+            Ok(SqlValue::Text(text)) => Ok(text),
+            _ => Err(format!("Expect {expected} in #![{name}(...)]")),
+        })
+        .collect()
+}
+
 fn normalize_table_name(table_name: &str) -> String {
     if table_name.contains('.') {
         return table_name.to_owned();
@@ -242,9 +809,9 @@ fn sql_value_from_value(value: Value) -> Result<SqlValue, String> {
                 .map_err(|err| format!("Cannot parse float: {err}"))?;
             Ok(SqlValue::Float(float_val))
         }
-        Value::Str(Str::Baked(content)) => {
-            Ok(SqlValue::Text(content.to_string()))
-        }
+        Value::Str(Str::Baked(content)) => Ok(SqlValue::Text(
+            unescape_template_calls(content).into_owned(),
+        )),
         Value::Str(Str::Raw { content: v, .. }) => {
             Ok(SqlValue::Text(v.to_string()))
         }
@@ -254,6 +821,76 @@ fn sql_value_from_value(value: Value) -> Result<SqlValue, String> {
     }
 }
 
+/// Resolves the `\"` and `\\` escapes inside the `{name(...)}` template
+/// calls of a baked RON string, so their arguments can be written as
+/// `"{date_between(\"2024-01-01\", ...)}"`. The rest of the string is kept
+/// as written.
+fn unescape_template_calls(content: &str) -> Cow<'_, str> {
+    if !content.contains('\\') {
+        return Cow::Borrowed(content);
+    }
+
+    let mut unescaped = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find('{') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = template_call_end(rest) else {
+            unescaped.push('{');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let mut chars = rest[..end].chars();
+        while let Some(c) = chars.next() {
+            match (c, chars.clone().next()) {
+                ('\\', Some(next @ ('"' | '\\'))) => {
+                    unescaped.push(next);
+                    chars.next();
+                }
+                _ => unescaped.push(c),
+            }
+        }
+
+        rest = &rest[end..];
+    }
+
+    unescaped.push_str(rest);
+    Cow::Owned(unescaped)
+}
+
+/// Length of the `{name(...)}` call at the start of `text`, its escaped
+/// strings may contain braces
+fn template_call_end(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('{')?.trim_start();
+    let name_end = inner
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|&end| end > 0)?;
+
+    if !inner[name_end..].trim_start().starts_with('(') {
+        return None;
+    }
+
+    let mut in_string = false;
+    let mut chars = text.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, '"')) = chars.next() {
+                    in_string = !in_string;
+                }
+            }
+            '}' if !in_string => return Some(i + 1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
 fn fields_from_value(
     value: Value,
     table_name: &str,
@@ -352,3 +989,138 @@ fn extract_schema_name(
         _ => None,
     })
 }
+
+/// Extract locale from inline attributes
+fn extract_locale(attributes: &[&ron_next::InlineAttribute]) -> Option<String> {
+    attributes.iter().find_map(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue {
+            ident,
+            value:
+                ron_next::Value::Str(
+                    ron_next::Str::Baked(content)
+                    | ron_next::Str::Raw { content, .. },
+                ),
+            ..
+        } if *ident == "locale" => Some(content.to_string()),
+        _ => None,
+    })
+}
+
+/// Extract the row template of `#[template = "..."]` from inline attributes
+fn extract_template(
+    attributes: &[&ron_next::InlineAttribute],
+) -> Option<String> {
+    attributes.iter().find_map(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue {
+            ident,
+            value:
+                ron_next::Value::Str(
+                    ron_next::Str::Baked(content)
+                    | ron_next::Str::Raw { content, .. },
+                ),
+            ..
+        } if *ident == "template" => Some(content.to_string()),
+        _ => None,
+    })
+}
+
+/// Extract the file of `#[csv = "..."]` or `#[json = "..."]` from inline
+/// attributes
+fn extract_data_file(
+    attributes: &[&ron_next::InlineAttribute],
+) -> Result<Option<DataFile>, String> {
+    let csv = extract_path(attributes, "csv").map(DataFile::Csv);
+    let json = extract_path(attributes, "json").map(DataFile::Json);
+
+    match (csv, json) {
+        (Some(_), Some(_)) => {
+            Err("#[csv] cannot be used with #[json]".to_string())
+        }
+        (csv, json) => Ok(csv.or(json)),
+    }
+}
+
+/// Extract the path of an attribute such as `#[csv = "..."]`
+fn extract_path(
+    attributes: &[&ron_next::InlineAttribute],
+    name: &str,
+) -> Option<PathBuf> {
+    attributes.iter().find_map(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue {
+            ident,
+            value:
+                ron_next::Value::Str(
+                    ron_next::Str::Baked(content)
+                    | ron_next::Str::Raw { content, .. },
+                ),
+            ..
+        } if *ident == name => Some(PathBuf::from(content)),
+        _ => None,
+    })
+}
+
+/// Extract `#[reset_sequence = false]` from inline attributes
+fn extract_keep_sequence(attributes: &[&ron_next::InlineAttribute]) -> bool {
+    attributes.iter().any(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue { ident, value, .. }
+            if *ident == "reset_sequence" =>
+        {
+            matches!(value, Value::Bool(false) | Value::Unit("false"))
+        }
+        _ => false,
+    })
+}
+
+/// Whether a bare attribute such as `#[sql]` is present
+fn extract_flag(attributes: &[&ron_next::InlineAttribute], name: &str) -> bool {
+    attributes.iter().any(|attr| {
+        matches!(*attr, ron_next::InlineAttribute::Ident { ident, .. } if *ident == name)
+    })
+}
+
+/// Extract `#[render = true]` from inline attributes, ron-next reads the
+/// `true` of an attribute as a unit
+fn extract_render(attributes: &[&ron_next::InlineAttribute]) -> bool {
+    attributes.iter().any(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue { ident, value, .. }
+            if *ident == "render" =>
+        {
+            matches!(value, Value::Bool(true) | Value::Unit("true"))
+        }
+        _ => false,
+    })
+}
+
+/// Extract the fields of `#[unique = ["email", "username"]]`, a single
+/// string is accepted too
+fn extract_unique_columns(
+    attributes: &[&ron_next::InlineAttribute],
+) -> Result<Vec<String>, String> {
+    let Some(value) = attributes.iter().find_map(|attr| match *attr {
+        ron_next::InlineAttribute::KeyValue { ident, value, .. }
+            if *ident == "unique" =>
+        {
+            Some(value)
+        }
+        _ => None,
+    }) else {
+        return Ok(vec![]);
+    };
+
+    let column = |value: &Value| match value {
+        Value::Str(Str::Baked(content) | Str::Raw { content, .. }) => {
+            Ok(content.to_string())
+        }
+        _ => Err("Expected field names in #[unique = [...]]".to_owned()),
+    };
+
+    match value {
+        Value::List(list) => list
+            .0
+            .values
+            .iter()
+            .map(|item| column(&item.content))
+            .collect(),
+        value => column(value).map(|column| vec![column]),
+    }
+}
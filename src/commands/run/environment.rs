@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use super::entry::Seeder;
+
+/// Whether a seeder with the environments of `#![env(...)]` runs in
+/// `active`. A seeder without them runs in all, otherwise one of them must
+/// be active.
+pub fn matches(environments: &[String], active: Option<&str>) -> bool {
+    environments.is_empty()
+        || active.is_some_and(|active| {
+            environments.iter().any(|environment| environment == active)
+        })
+}
+
+/// Environments of the seeder `name` of the `root` folder
+pub fn seeder_environments(
+    root: &Path,
+    name: &str,
+) -> Result<Vec<String>, String> {
+    Ok(Seeder::read(root, name)?.environments)
+}
+
+/// Seeders of `names` that run in the active environment. Seeders that
+/// cannot be read are kept, so running them reports the error.
+pub fn filter(
+    root: &Path,
+    names: Vec<String>,
+    active: Option<&str>,
+) -> Vec<String> {
+    names
+        .into_iter()
+        .filter(|name| match seeder_environments(root, name) {
+            Ok(environments) => matches(&environments, active),
+            Err(_) => true,
+        })
+        .collect()
+}
+
+/// Error when the seeder `name` does not run in the active environment
+pub fn check(
+    root: &Path,
+    name: &str,
+    active: Option<&str>,
+) -> Result<(), String> {
+    let environments = seeder_environments(root, name)?;
+
+    if matches(&environments, active) {
+        return Ok(());
+    }
+
+    let active = match active {
+        Some(active) => format!("not in {active}"),
+        None => "but no environment is set with --env or GROW_ENV".to_owned(),
+    };
+
+    Err(format!(
+        "{name} only runs in {}, {active}. Use --force to run it anyway",
+        environments.join(", ")
+    ))
+}
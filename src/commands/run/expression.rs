@@ -0,0 +1,608 @@
+use std::cmp::Ordering;
+
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+use srtemplate::SrTemplate;
+
+/// Characters that srtemplate cannot read inside `{...}`, a block containing
+/// one of them outside a string is evaluated by [`evaluate`]
+const OPERATORS: &[u8] = b"+-*/%=!<>&|:";
+
+/// Prefix of the temporary variables used to pass evaluated arguments to
+/// template functions
+const ARGUMENT_PREFIX: &str = "grow_argument_";
+
+/// `if(condition, then, else)`: `then` when the condition is truthy
+pub fn if_then_else(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 3)?;
+    validations::args_max_len(args, 3)?;
+
+    Ok(if is_truthy(&args[0]) {
+        args[1].to_owned()
+    } else {
+        args[2].to_owned()
+    })
+}
+
+/// `choice(value, ...)`: one of the values, picked at random
+pub fn choice(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+
+    let index = rand::random_range(0..args.len());
+
+    Ok(args[index].to_owned())
+}
+
+/// `weighted("value":weight, ...)`: one of the values, picked with a
+/// probability proportional to its weight
+pub fn weighted(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+
+    let options = args
+        .iter()
+        .map(|arg| {
+            let (value, weight) = arg.rsplit_once(':').ok_or_else(|| {
+                function::Error::InvalidArgument(format!(
+                    "weighted: expected \"value\":weight, found {arg}"
+                ))
+            })?;
+            let weight = weight
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| function::Error::InvalidType(weight.to_owned()))?;
+
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(function::Error::InvalidArgument(format!(
+                    "weighted: invalid weight in {arg}"
+                )));
+            }
+
+            Ok((value, weight))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total: f64 = options.iter().map(|(_, weight)| weight).sum();
+    if total <= 0.0 {
+        return Err(function::Error::RuntimeError(
+            "weighted: the weights add up to 0".to_owned(),
+        ));
+    }
+
+    let mut target = rand::random_range(0.0..total);
+    for (value, weight) in &options {
+        if target < *weight {
+            return Ok((*value).to_owned());
+        }
+        target -= weight;
+    }
+
+    // Rounding can leave the target past the last weight
+    let (value, _) = options
+        .iter()
+        .rev()
+        .find(|(_, weight)| *weight > 0.0)
+        .unwrap_or(&options[0]);
+    Ok((*value).to_owned())
+}
+
+/// Whether the content of a `{...}` block is a variable, a function call or
+/// an expression, which start with a name, a number, a string, `(` or a
+/// unary operator. Other blocks, as the `{"a":1}` of JSON text, are text.
+pub fn is_template(block: &str) -> bool {
+    starts_expression(block.trim_start())
+}
+
+/// Whether the content of a `{...}` block is an expression with operators
+pub fn needs_evaluation(expression: &str) -> bool {
+    if !is_template(expression) {
+        return false;
+    }
+
+    let (mut in_string, mut escaped) = (false, false);
+
+    for byte in expression.bytes() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            _ if OPERATORS.contains(&byte) => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+fn starts_expression(expression: &str) -> bool {
+    match expression.bytes().next() {
+        Some(
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'0'..=b'9' | b'(' | b'-' | b'!',
+        ) => true,
+        // A string followed by `:` is the key of a JSON object
+        Some(b'"') => !string_end(expression)
+            .is_some_and(|end| expression[end..].trim_start().starts_with(':')),
+        _ => false,
+    }
+}
+
+/// Position after the string that starts `text`
+fn string_end(text: &str) -> Option<usize> {
+    let mut escaped = false;
+
+    for (i, byte) in text.bytes().enumerate().skip(1) {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'"' => return Some(i + 1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Evaluates the content of a `{...}` block with arithmetic, comparison and
+/// logical operators. Variables and function calls are resolved by the
+/// template, `"value":weight` arguments are passed as `value:weight`.
+pub fn evaluate(
+    templating: &SrTemplate,
+    expression: &str,
+) -> Result<String, String> {
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        templating,
+        tokens,
+        position: 0,
+    };
+
+    let value = parser.expression()?;
+
+    match parser.peek() {
+        None => Ok(value.to_string()),
+        Some(token) => Err(format!("Unexpected `{token}` in `{expression}`")),
+    }
+}
+
+/// `false` for empty text, `false`, `null` and zero
+fn is_truthy(text: &str) -> bool {
+    let text = text.trim();
+
+    !(text.is_empty()
+        || text.eq_ignore_ascii_case("false")
+        || text.eq_ignore_ascii_case("null")
+        || text.parse::<f64>().is_ok_and(|number| number == 0.0))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Str(String),
+    Ident(String),
+    Operator(&'static str),
+    Open,
+    Close,
+    Comma,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(text) | Token::Ident(text) => write!(f, "{text}"),
+            Token::Str(text) => write!(f, "\"{text}\""),
+            Token::Operator(operator) => write!(f, "{operator}"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+        }
+    }
+}
+
+/// Operators by length, so `==` is read before `=`
+const OPERATOR_TOKENS: &[&str] = &[
+    "==", "!=", "<=", ">=", "&&", "||", "+", "-", "*", "/", "%", "<", ">", "!",
+    ":",
+];
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = expression.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | ',' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::Open,
+                    ')' => Token::Close,
+                    _ => Token::Comma,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut text = String::new();
+                let mut closed = false;
+
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        '\\' => match chars.next() {
+                            Some((_, 'n')) => text.push('\n'),
+                            Some((_, 't')) => text.push('\t'),
+                            Some((_, c)) => text.push(c),
+                            None => break,
+                        },
+                        c => text.push(c),
+                    }
+                }
+
+                if !closed {
+                    return Err(format!(
+                        "Unterminated string in `{expression}`"
+                    ));
+                }
+                tokens.push(Token::Str(text));
+            }
+            _ if c.is_ascii_digit() => {
+                let end =
+                    take_while(&mut chars, |c| c.is_ascii_digit() || c == '.');
+                tokens.push(Token::Number(expression[start..end].to_owned()));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let end =
+                    take_while(&mut chars, |c| c.is_alphanumeric() || c == '_');
+                tokens.push(Token::Ident(expression[start..end].to_owned()));
+            }
+            _ => {
+                let rest = &expression[start..];
+                let Some(operator) = OPERATOR_TOKENS
+                    .iter()
+                    .find(|operator| rest.starts_with(**operator))
+                else {
+                    return Err(format!("Unexpected `{c}` in `{expression}`"));
+                };
+
+                for _ in 0..operator.len() {
+                    chars.next();
+                }
+                tokens.push(Token::Operator(operator));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn take_while(
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    predicate: impl Fn(char) -> bool,
+) -> usize {
+    let mut end = 0;
+    while let Some(&(index, c)) = chars.peek() {
+        if !predicate(c) {
+            return index;
+        }
+        end = index + c.len_utf8();
+        chars.next();
+    }
+    end
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl Value {
+    /// Numeric view of the value, text coming from variables or functions
+    /// is parsed
+    fn number(&self) -> Option<Value> {
+        match self {
+            Value::Int(_) | Value::Float(_) => Some(self.clone()),
+            Value::Text(text) => {
+                let text = text.trim();
+                text.parse::<i64>()
+                    .map(Value::Int)
+                    .or_else(|_| text.parse::<f64>().map(Value::Float))
+                    .ok()
+            }
+            Value::Bool(_) => None,
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        match self {
+            Value::Bool(value) => *value,
+            Value::Int(value) => *value != 0,
+            Value::Float(value) => *value != 0.0,
+            Value::Text(text) => is_truthy(text),
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value}"),
+            Value::Text(value) => write!(f, "{value}"),
+            Value::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+struct Parser<'a, 't> {
+    templating: &'a SrTemplate<'t>,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_, '_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Operator(operator)) if operators.contains(operator) => {
+                let operator = *operator;
+                self.position += 1;
+                Some(operator)
+            }
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => {
+                Err(format!("Expected `{expected}`, found `{token}`"))
+            }
+            None => Err(format!("Expected `{expected}`")),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Value, String> {
+        let mut left = self.and()?;
+        while self.eat(&["||"]).is_some() {
+            let right = self.and()?;
+            left = Value::Bool(left.is_truthy() || right.is_truthy());
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Value, String> {
+        let mut left = self.comparison()?;
+        while self.eat(&["&&"]).is_some() {
+            let right = self.comparison()?;
+            left = Value::Bool(left.is_truthy() && right.is_truthy());
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Result<Value, String> {
+        let left = self.sum()?;
+        let Some(operator) = self.eat(&["==", "!=", "<=", ">=", "<", ">"])
+        else {
+            return Ok(left);
+        };
+        let right = self.sum()?;
+
+        let ordering = match (left.number(), right.number()) {
+            (Some(Value::Int(l)), Some(Value::Int(r))) => l.partial_cmp(&r),
+            (Some(l), Some(r)) => as_float(&l).partial_cmp(&as_float(&r)),
+            _ => left.to_string().partial_cmp(&right.to_string()),
+        };
+
+        Ok(Value::Bool(match operator {
+            "==" => ordering == Some(Ordering::Equal),
+            "!=" => ordering != Some(Ordering::Equal),
+            "<" => ordering == Some(Ordering::Less),
+            ">" => ordering == Some(Ordering::Greater),
+            "<=" => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            _ => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        }))
+    }
+
+    fn sum(&mut self) -> Result<Value, String> {
+        let mut left = self.product()?;
+        while let Some(operator) = self.eat(&["+", "-"]) {
+            let right = self.product()?;
+
+            left = match (operator, left.number(), right.number()) {
+                ("+", None, _) | ("+", _, None) => {
+                    Value::Text(format!("{left}{right}"))
+                }
+                (_, Some(l), Some(r)) => arithmetic(operator, l, r)?,
+                _ => {
+                    return Err(format!(
+                        "Cannot subtract `{right}` from `{left}`"
+                    ))
+                }
+            };
+        }
+        Ok(left)
+    }
+
+    fn product(&mut self) -> Result<Value, String> {
+        let mut left = self.unary()?;
+        while let Some(operator) = self.eat(&["*", "/", "%"]) {
+            let right = self.unary()?;
+
+            left = match (left.number(), right.number()) {
+                (Some(l), Some(r)) => arithmetic(operator, l, r)?,
+                _ => {
+                    return Err(format!(
+                        "Cannot apply `{operator}` to `{left}` and `{right}`"
+                    ))
+                }
+            };
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Value, String> {
+        match self.eat(&["-", "!"]) {
+            Some("!") => Ok(Value::Bool(!self.unary()?.is_truthy())),
+            Some(_) => {
+                let value = self.unary()?;
+                match value.number() {
+                    Some(number) => arithmetic("-", Value::Int(0), number),
+                    None => Err(format!("Cannot negate `{value}`")),
+                }
+            }
+            None => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Value, String> {
+        match self.next() {
+            Some(Token::Number(number)) => Value::Text(number.clone())
+                .number()
+                .ok_or_else(|| format!("Invalid number `{number}`")),
+            Some(Token::Str(text)) => Ok(Value::Text(text)),
+            Some(Token::Ident(ident)) if ident == "true" => {
+                Ok(Value::Bool(true))
+            }
+            Some(Token::Ident(ident)) if ident == "false" => {
+                Ok(Value::Bool(false))
+            }
+            Some(Token::Ident(ident)) => {
+                if self.peek() == Some(&Token::Open) {
+                    self.position += 1;
+                    self.call(&ident)
+                } else {
+                    self.render(&format!("{{{ident}}}")).map(Value::Text)
+                }
+            }
+            Some(Token::Open) => {
+                let value = self.expression()?;
+                self.expect(Token::Close)?;
+                Ok(value)
+            }
+            Some(token) => Err(format!("Unexpected `{token}`")),
+            None => Err("Unexpected end of expression".to_owned()),
+        }
+    }
+
+    /// Evaluates the arguments and calls the template function through
+    /// temporary variables, so evaluated text is passed as is
+    fn call(&mut self, name: &str) -> Result<Value, String> {
+        let mut arguments = vec![];
+
+        if self.peek() == Some(&Token::Close) {
+            self.position += 1;
+        } else {
+            loop {
+                let mut argument = self.expression()?.to_string();
+                if self.eat(&[":"]).is_some() {
+                    argument = format!("{argument}:{}", self.expression()?);
+                }
+                arguments.push(argument);
+
+                match self.next() {
+                    Some(Token::Comma) => continue,
+                    Some(Token::Close) => break,
+                    Some(token) => {
+                        return Err(format!(
+                            "Expected `,` or `)` in {name}, found `{token}`"
+                        ))
+                    }
+                    None => return Err(format!("Unclosed call to {name}")),
+                }
+            }
+        }
+
+        let names: Vec<String> = (0..arguments.len())
+            .map(|index| format!("{ARGUMENT_PREFIX}{index}"))
+            .collect();
+
+        for (name, argument) in names.iter().zip(&arguments) {
+            self.templating.add_variable(name.clone(), argument);
+        }
+
+        let result = self.render(&format!("{{{name}({})}}", names.join(", ")));
+
+        for name in &names {
+            self.templating.remove_variable(name.clone());
+        }
+
+        result.map(Value::Text)
+    }
+
+    fn render(&self, template: &str) -> Result<String, String> {
+        self.templating
+            .render(template)
+            .map_err(|err| err.to_string())
+    }
+}
+
+fn as_float(value: &Value) -> f64 {
+    match value {
+        Value::Int(value) => *value as f64,
+        Value::Float(value) => *value,
+        _ => f64::NAN,
+    }
+}
+
+/// Integer arithmetic when both sides are integers, float otherwise
+fn arithmetic(
+    operator: &str,
+    left: Value,
+    right: Value,
+) -> Result<Value, String> {
+    if let (Value::Int(l), Value::Int(r)) = (&left, &right) {
+        let (l, r) = (*l, *r);
+        if matches!(operator, "/" | "%") && r == 0 {
+            return Err(format!("Division by zero in `{l} {operator} {r}`"));
+        }
+
+        let result = match operator {
+            "+" => l.checked_add(r),
+            "-" => l.checked_sub(r),
+            "*" => l.checked_mul(r),
+            "/" => l.checked_div(r),
+            _ => l.checked_rem(r),
+        };
+
+        return result
+            .map(Value::Int)
+            .ok_or_else(|| format!("Overflow in `{l} {operator} {r}`"));
+    }
+
+    let (l, r) = (as_float(&left), as_float(&right));
+    if matches!(operator, "/" | "%") && r == 0.0 {
+        return Err(format!("Division by zero in `{l} {operator} {r}`"));
+    }
+
+    Ok(Value::Float(match operator {
+        "+" => l + r,
+        "-" => l - r,
+        "*" => l * r,
+        "/" => l / r,
+        _ => l % r,
+    }))
+}
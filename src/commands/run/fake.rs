@@ -1,22 +1,72 @@
+use std::sync::RwLock;
+
 use srtemplate::function::{self, FuncResult};
 use srtemplate::prelude::validations;
 
-use super::fake_generated;
+use super::fake_generated::{self, LOCALES};
+
+/// Locale of `grow run --locale`
+static RUN_LOCALE: RwLock<usize> = RwLock::new(0);
 
+/// Locale of the `#[locale = "..."]` of the entry being rendered
+static ENTRY_LOCALE: RwLock<Option<usize>> = RwLock::new(None);
+
+/// `fake(KIND, ...)`: value of a faker, the extra arguments are passed to
+/// the fakers that take them, e.g. `fake(WORDS, 3, 6)`
 pub fn fake(args: &[String]) -> FuncResult {
     validations::args_min_len(args, 1)?;
-    validations::args_max_len(args, 1)?;
 
     let kind = &args[0];
     let Ok(kind) = kind.parse::<u16>() else {
         return Err(function::Error::InvalidType(kind.to_owned()));
     };
 
-    let Some(result) = fake_generated::execute_faker(kind) else {
-        return Err(function::Error::RuntimeError(format!(
-            "Fake kind is not valid: {kind}"
-        )));
-    };
+    fake_generated::execute_faker(localize(kind), &args[1..])
+        .map_err(function::Error::RuntimeError)
+}
+
+/// Sets the locale of plain kinds such as `FIRST_NAME` for the whole run
+pub fn set_run_locale(code: &str) {
+    if let Ok(mut locale) = RUN_LOCALE.write() {
+        *locale = locale_index(code);
+    }
+}
+
+/// Sets the locale of the entry being rendered, `None` uses the locale of
+/// the run
+pub fn set_entry_locale(code: Option<&str>) {
+    if let Ok(mut locale) = ENTRY_LOCALE.write() {
+        *locale = code.map(locale_index);
+    }
+}
+
+/// Position of a locale such as `PT_BR` or `pt-BR` in [`LOCALES`], unknown
+/// locales fall back to English with a warning
+pub fn locale_index(code: &str) -> usize {
+    let normalized = code.trim().to_uppercase().replace('-', "_");
+
+    match LOCALES.iter().position(|(_, locale)| *locale == normalized) {
+        Some(index) => index,
+        None => {
+            eprintln!(
+                "\x1b[1;33m[WARN] Locale {code} is not supported by fake, falling back to EN\x1b[0m"
+            );
+            0
+        }
+    }
+}
+
+/// Kinds are generated per faker in the order of [`LOCALES`], English
+/// first, so a plain kind is moved to the active locale. Kinds with an
+/// explicit locale, as `FIRST_NAME_JA_JP`, are kept.
+fn localize(kind: u16) -> u16 {
+    let locales = LOCALES.len() as u16;
+    if !kind.is_multiple_of(locales) {
+        return kind;
+    }
+
+    let entry = ENTRY_LOCALE.read().ok().and_then(|locale| *locale);
+    let run = RUN_LOCALE.read().map(|locale| *locale).unwrap_or_default();
 
-    Ok(result)
+    kind + entry.unwrap_or(run) as u16
 }
@@ -1,23 +1,260 @@
+use std::ops::Range;
+
+/// Arguments of the fakers that take them, read in order from the extra
+/// arguments of `fake(KIND, ...)`
+pub trait FakerArgument<'a>: Sized {
+    fn parse(
+        name: &str,
+        args: &mut std::slice::Iter<'a, String>,
+    ) -> Result<Self, String>;
+}
+
+fn next_argument<'a>(
+    name: &str,
+    args: &mut std::slice::Iter<'a, String>,
+) -> Result<&'a str, String> {
+    args.next()
+        .map(|arg| arg.trim())
+        .ok_or_else(|| format!("Missing argument `{name}`"))
+}
+
+fn parse_number<T: std::str::FromStr>(
+    name: &str,
+    arg: &str,
+) -> Result<T, String> {
+    arg.parse()
+        .map_err(|_| format!("Invalid number for `{name}`: {arg}"))
+}
+
+impl<'a> FakerArgument<'a> for u8 {
+    fn parse(
+        name: &str,
+        args: &mut std::slice::Iter<'a, String>,
+    ) -> Result<Self, String> {
+        parse_number(name, next_argument(name, args)?)
+    }
+}
+
+/// Two arguments, `min` and `max`, both inclusive
+impl<'a> FakerArgument<'a> for Range<usize> {
+    fn parse(
+        name: &str,
+        args: &mut std::slice::Iter<'a, String>,
+    ) -> Result<Self, String> {
+        let min = parse_number(name, next_argument(name, args)?)?;
+        let max = parse_number(name, next_argument(name, args)?)?;
+
+        if min > max {
+            return Err(format!("`{name}`: {min} is greater than {max}"));
+        }
+
+        Ok(min..max + 1)
+    }
+}
+
+impl<'a> FakerArgument<'a> for &'a str {
+    fn parse(
+        name: &str,
+        args: &mut std::slice::Iter<'a, String>,
+    ) -> Result<Self, String> {
+        next_argument(name, args)
+    }
+}
+
+impl<'a> FakerArgument<'a> for chrono::DateTime<chrono::Utc> {
+    fn parse(
+        name: &str,
+        args: &mut std::slice::Iter<'a, String>,
+    ) -> Result<Self, String> {
+        super::datetime::parse_timestamp(next_argument(name, args)?)
+            .map(|datetime| datetime.and_utc())
+    }
+}
+
+/// Text of the values generated by the fakers that don't produce a `String`
+pub trait FakerOutput {
+    fn into_text(self) -> String;
+}
+
+impl FakerOutput for String {
+    fn into_text(self) -> String {
+        self
+    }
+}
+
+impl FakerOutput for Vec<String> {
+    fn into_text(self) -> String {
+        self.join(" ")
+    }
+}
+
+impl FakerOutput for bool {
+    fn into_text(self) -> String {
+        self.to_string()
+    }
+}
+
+impl FakerOutput for chrono::Duration {
+    fn into_text(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Decimals of the setup. The ones of `fake` 3.1 get a random scale, which
+/// panics in `rust_decimal` once it is over 28, so these have up to
+/// [`DIGITS`](decimal::DIGITS) integer digits and [`SCALE`](decimal::SCALE)
+/// decimals: they fit a `DECIMAL(12, 4)` column.
+mod decimal {
+    use fake::rand::Rng;
+    use fake::Dummy;
+
+    pub const DIGITS: u32 = 8;
+    pub const SCALE: u32 = 4;
+
+    pub struct Decimal;
+    pub struct PositiveDecimal;
+    pub struct NegativeDecimal;
+    pub struct NoDecimalPoints;
+
+    /// Text of a decimal of at least `min` in its last digit, with up to
+    /// `scale` decimals
+    fn decimal<R: Rng + ?Sized>(
+        rng: &mut R,
+        negative: bool,
+        min: u64,
+        scale: u32,
+    ) -> String {
+        let scale = rng.gen_range(0..=scale);
+        let unit = 10u64.pow(scale);
+        let value = rng.gen_range(min..10u64.pow(DIGITS) * unit);
+
+        let sign = if negative && value != 0 { "-" } else { "" };
+        let (integer, fraction) = (value / unit, value % unit);
+
+        match scale {
+            0 => format!("{sign}{integer}"),
+            scale => format!(
+                "{sign}{integer}.{fraction:0width$}",
+                width = scale as usize
+            ),
+        }
+    }
+
+    impl Dummy<Decimal> for String {
+        fn dummy_with_rng<R: Rng + ?Sized>(_: &Decimal, rng: &mut R) -> Self {
+            let negative = rng.gen();
+            decimal(rng, negative, 0, SCALE)
+        }
+    }
+
+    impl Dummy<PositiveDecimal> for String {
+        fn dummy_with_rng<R: Rng + ?Sized>(
+            _: &PositiveDecimal,
+            rng: &mut R,
+        ) -> Self {
+            decimal(rng, false, 1, SCALE)
+        }
+    }
+
+    impl Dummy<NegativeDecimal> for String {
+        fn dummy_with_rng<R: Rng + ?Sized>(
+            _: &NegativeDecimal,
+            rng: &mut R,
+        ) -> Self {
+            decimal(rng, true, 1, SCALE)
+        }
+    }
+
+    impl Dummy<NoDecimalPoints> for String {
+        fn dummy_with_rng<R: Rng + ?Sized>(
+            _: &NoDecimalPoints,
+            rng: &mut R,
+        ) -> Self {
+            let negative = rng.gen();
+            decimal(rng, negative, 0, 0)
+        }
+    }
+}
+
+/// A faker of the setup, `kind` is the kind of its English variant
+pub struct Faker {
+    pub module: &'static str,
+    pub name: &'static str,
+    pub kind: u16,
+    /// Arguments as `(name, type)`
+    pub arguments: &'static [(&'static str, &'static str)],
+    /// The only locale of the fakers implemented for a single one
+    pub only: Option<&'static str>,
+}
+
+impl Faker {
+    /// Name of the kind in a locale of [`LOCALES`], e.g. `FIRST_NAME_JA_JP`
+    pub fn name_in(&self, locale: usize) -> String {
+        match LOCALES[locale].1 {
+            "EN" => self.name.to_owned(),
+            code => format!("{}_{code}", self.name),
+        }
+    }
+
+    pub fn kind_in(&self, locale: usize) -> u16 {
+        self.kind + locale as u16
+    }
+
+    /// Usage of the faker, e.g. `fake(WORDS, min, max)`
+    pub fn signature(&self) -> String {
+        let arguments = self.arguments.iter().map(|(name, ty)| match *ty {
+            "Range<usize>" => "min, max",
+            _ => name,
+        });
+
+        let arguments = std::iter::once(self.name)
+            .chain(arguments)
+            .collect::<Vec<_>>();
+
+        format!("fake({})", arguments.join(", "))
+    }
+}
+
 macro_rules! setup_faker {
     (
         $($name:literal = $locale:ident,)+;
         $(
         mod $mod:ident {
-            $($fn:ident = $var:ident);*
+            $(
+                $fn:ident $(( $($arg:ident: $ty:ty),+ ))?
+                = $var:ident $(in $only:ident)? $(-> $out:ty)?
+            );*
             $(;)?
         }
         )*
     ) => {
         use fake::Fake;
 
+        /// Supported locales as `(name, code)`, in the order of the kinds of
+        /// every faker
+        pub const LOCALES: &[(&str, &str)] = &[$(($name, stringify!($locale)),)+];
+
+        /// Every faker of the setup
+        pub const FAKERS: &[Faker] = &[$($(Faker {
+            module: stringify!($mod),
+            name: stringify!($var),
+            kind: $var,
+            arguments: &[$($((stringify!($arg), stringify!($ty))),+)?],
+            only: setup_faker!(@only $($only)?),
+        },)*)*];
+
         setup_faker! {@consts [$(( $locale ))+]; [] [$($($var ,)*)*]}
 
         pub fn setup_faker_variables(_sr_template: &::srtemplate::SrTemplate) {
             setup_faker! {@vars [_sr_template] [$(( $locale ))+]; [ $($( $var ,)*)* ]}
         }
 
-        pub fn execute_faker(kind: u16) -> Option<String> {
-            setup_faker! (@match [kind] [$(( $locale ))+]; [ $($($mod => $fn = $var;)*)* ])
+        /// Generates a value of `kind`, `args` are the arguments of the
+        /// fakers that take them
+        pub fn execute_faker(kind: u16, args: &[String]) -> Result<String, String> {
+            setup_faker! (@match [kind, args] [$(( $locale ))+]; [
+                $($($mod => $fn [$($($arg: $ty),+)?] [$($only)?] [$($out)?] = $var;)*)*
+            ])
         }
     };
 
@@ -77,10 +314,9 @@ macro_rules! setup_faker {
     //        MATCHS         //
     ///////////////////////////
     (@match
-        [$kind:ident]
+        [$kind:ident, $args:ident]
         $locales:tt;
-        [ $($mod:ident => $fn:ident = $var:ident;)* ]
-        // $mods:tt
+        [ $($mod:ident => $fn:ident $params:tt $only:tt $out:tt = $var:ident;)* ]
     ) => {
         match $kind {
             $(_
@@ -89,20 +325,73 @@ macro_rules! setup_faker {
                     ..=
                     setup_faker!(@match-key-end $locales; $var)
                 ).contains(&$kind)
-                => setup_faker! (@match-value [$kind] $locales; $mod => $fn = $var),
-                // => ,
+                => setup_faker! (@match-value [$kind, $args] $locales; $mod => $fn $params $only $out = $var),
             )*
-            _ => None
+            _ => Err(format!("Fake kind is not valid: {}", $kind))
         }
     };
 
-    (@match-value [$kind:ident] [$(( $locale:ident ))+]; $mod:ident => $fn:ident = $var:ident) => {
+    (@match-value
+        [$kind:ident, $args:ident]
+        [$(( $locale:ident ))+];
+        $mod:ident => $fn:ident $params:tt $only:tt $out:tt = $var:ident
+    ) => {
         match $kind {
-            $(setup_faker!(@match-key [$locale]; $var) => Some(::fake::faker::$mod::raw::$fn(::fake::locales::$locale).fake()),)+
+            $(setup_faker!(@match-key [$locale]; $var) => setup_faker!(@faker [$args] [$locale] $only; $mod => $fn $params $out = $var),)+
             _ => unsafe { ::core::hint::unreachable_unchecked() }
         }
     };
 
+    ///////////////////////////
+    //        FAKERS         //
+    ///////////////////////////
+
+    // Fakers implemented for a single locale use it whatever the kind
+    (@faker [$args:ident] [$locale:ident] [$only:ident]; $($faker:tt)*) => {
+        setup_faker!(@faker [$args] [$only] []; $($faker)*)
+    };
+
+    (@faker
+        [$args:ident] [$locale:ident] [];
+        $mod:ident => $fn:ident [$($arg:ident: $ty:ty),*] [$($out:ty)?] = $var:ident
+    ) => {{
+        let mut args = $args.iter();
+        $(
+            let $arg = <$ty as FakerArgument>::parse(stringify!($arg), &mut args)
+                .map_err(|err| format!("{}: {err}", stringify!($var)))?;
+        )*
+
+        if args.next().is_some() {
+            return Err(format!("Too many arguments for {}", stringify!($var)));
+        }
+
+        let value = setup_faker!(@new $mod $fn [$locale] [$($arg),*])
+            .fake::<setup_faker!(@output $($out)?)>();
+        Ok(FakerOutput::into_text(value))
+    }};
+
+    // `uuid` is not localized
+    (@new uuid $fn:ident $locale:tt []) => {
+        ::fake::uuid::$fn
+    };
+
+    // `decimal` is not localized either, its fakers are bounded by [`decimal`]
+    (@new decimal $fn:ident $locale:tt []) => {
+        decimal::$fn
+    };
+
+    (@new $mod:ident $fn:ident [$locale:ident] [$($arg:ident),*]) => {
+        ::fake::faker::$mod::raw::$fn(::fake::locales::$locale $(, $arg)*)
+    };
+
+    (@only) => { None };
+
+    (@only $only:ident) => { Some(stringify!($only)) };
+
+    (@output) => { String };
+
+    (@output $out:ty) => { $out };
+
     (@match-key [EN]; $var:ident ) => {
         $var
     };
@@ -124,10 +413,6 @@ macro_rules! setup_faker {
     };
 }
 
-// TODO(Brayan-724): Accept arguments in kinds.
-// This can be made creating functions instead of numbers
-// or by accepting more parameters in `fake` function
-
 /*=START-SETUP=*/
 setup_faker! {
     // Locales
@@ -136,7 +421,7 @@ setup_faker! {
     "German" = DE_DE,
     "French" = FR_FR,
     "Japanese" = JA_JP,
-    "Protugese Brazilian" = PT_BR,
+    "Portuguese Brazilian" = PT_BR,
     "Traditional Chinese" = ZH_TW,
     "Simplified Chinese" = ZH_CN,
     ;
@@ -159,7 +444,7 @@ setup_faker! {
         BuildingNumber = BUILDING_NUMBER;
         Latitude = LATITUDE;
         Longitude = LONGITUDE;
-        // Geohash(precision: u8) = GEOHASH;
+        Geohash(precision: u8) = GEOHASH;
     }
 
     mod barcode {
@@ -169,7 +454,26 @@ setup_faker! {
     }
 
     mod boolean {
-        // Boolean(ratio: u8) = BOOLEAN;
+        Boolean(ratio: u8) = BOOLEAN -> bool;
+    }
+
+    mod chrono {
+        Time = TIME;
+        Date = DATE;
+        DateTime = DATE_TIME;
+        Duration = DURATION -> chrono::Duration;
+        DateTimeBefore(dt: chrono::DateTime<chrono::Utc>) = DATE_TIME_BEFORE;
+        DateTimeAfter(dt: chrono::DateTime<chrono::Utc>) = DATE_TIME_AFTER;
+        DateTimeBetween(start: chrono::DateTime<chrono::Utc>, end: chrono::DateTime<chrono::Utc>) = DATE_TIME_BETWEEN;
+    }
+
+    mod color {
+        HexColor = HEX_COLOR;
+        RgbColor = RGB_COLOR;
+        RgbaColor = RGBA_COLOR;
+        HslColor = HSL_COLOR;
+        HslaColor = HSLA_COLOR;
+        Color = COLOR;
     }
 
     mod creditcard {
@@ -191,13 +495,18 @@ setup_faker! {
         Industry = INDUSTRY;
     }
 
+    mod http {
+        RfcStatusCode = RFC_STATUS_CODE;
+        ValidStatusCode = VALID_STATUS_CODE;
+    }
+
     mod internet {
         FreeEmailProvider = FREE_EMAIL_PROVIDER;
         DomainSuffix = DOMAIN_SUFFIX;
         FreeEmail = FREE_EMAIL;
         SafeEmail = SAFE_EMAIL;
         Username = USERNAME;
-        // Password(len_range: std::ops::Range<usize>) = PASSWORD;
+        Password(len_range: Range<usize>) = PASSWORD;
         IPv4 = IPV4;
         IPv6 = IPV6;
         IP = IP;
@@ -214,11 +523,11 @@ setup_faker! {
 
     mod lorem {
         Word = WORD;
-        // Words(count: std::ops::Range<usize>) = WORDS;
-        // Sentence(count: std::ops::Range<usize>) = SENTENCE;
-        // Sentences(count: std::ops::Range<usize>) = SENTENCES;
-        // Paragraph(count: std::ops::Range<usize>) = PARAGRAPH;
-        // Paragraphs(count: std::ops::Range<usize>) = PARAGRAPHS;
+        Words(count: Range<usize>) = WORDS -> Vec<String>;
+        Sentence(count: Range<usize>) = SENTENCE;
+        Sentences(count: Range<usize>) = SENTENCES -> Vec<String>;
+        Paragraph(count: Range<usize>) = PARAGRAPH;
+        Paragraphs(count: Range<usize>) = PARAGRAPHS -> Vec<String>;
     }
 
     mod name {
@@ -232,7 +541,7 @@ setup_faker! {
 
     mod number {
         Digit = DIGIT;
-        // NumberWithFormat<'a>(fmt: &'a str) = NUMBER_WITH_FORMAT;
+        NumberWithFormat(fmt: &str) = NUMBER_WITH_FORMAT;
     }
 
     mod phone_number {
@@ -262,13 +571,30 @@ setup_faker! {
         Isin = ISIN;
     }
 
-    // mod administrative {
-    //     HealthInsuranceCode = HEALTH_INSURANCE_CODE;
-    // }
+    mod administrative {
+        HealthInsuranceCode = HEALTH_INSURANCE_CODE in FR_FR;
+    }
+
+    mod automotive {
+        LicencePlate = LICENCE_PLATE in FR_FR;
+    }
 
-    // mod automotive {
-    //     LicencePlate = LICENCE_PLATE;
-    // }
+    mod uuid {
+        UUIDv1 = UUID_V1;
+        UUIDv3 = UUID_V3;
+        UUIDv4 = UUID_V4;
+        UUIDv5 = UUID_V5;
+        UUIDv6 = UUID_V6;
+        UUIDv7 = UUID_V7;
+        UUIDv8 = UUID_V8;
+    }
+
+    mod decimal {
+        Decimal = DECIMAL;
+        PositiveDecimal = POSITIVE_DECIMAL;
+        NegativeDecimal = NEGATIVE_DECIMAL;
+        NoDecimalPoints = NO_DECIMAL_POINTS;
+    }
 }
 // NOTE: Don't write anything below the setup.
 // This is used to auto documentation
@@ -0,0 +1,52 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHasher, SaltString};
+use argon2::Argon2;
+use sha2::{Digest, Sha256};
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+
+/// bcrypt cost used when `bcrypt` has no second argument. Lower than the
+/// crate default to keep large seeders fast.
+const BCRYPT_DEFAULT_COST: u32 = 10;
+
+/// `bcrypt(password[, cost])`: bcrypt hash with a random salt
+pub fn bcrypt(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 2)?;
+
+    let cost = match args.get(1) {
+        Some(cost) => cost
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| function::Error::InvalidType(cost.to_owned()))?,
+        None => BCRYPT_DEFAULT_COST,
+    };
+
+    bcrypt::hash(&args[0], cost).map_err(|err| {
+        function::Error::InvalidArgument(format!("bcrypt: {err}"))
+    })
+}
+
+/// `argon2(password)`: Argon2id hash in PHC format with a random salt
+pub fn argon2(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 1)?;
+
+    let salt = SaltString::generate(&mut OsRng);
+
+    Argon2::default()
+        .hash_password(args[0].as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|err| function::Error::RuntimeError(format!("argon2: {err}")))
+}
+
+/// `sha256(text)`: lowercase hexadecimal SHA-256 digest
+pub fn sha256(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 1)?;
+
+    Ok(Sha256::digest(args[0].as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
@@ -0,0 +1,72 @@
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+use uuid::Uuid;
+
+/// `uuid()`: random UUID v4
+pub fn uuid(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 0)?;
+
+    Ok(Uuid::new_v4().to_string())
+}
+
+/// `uuid_v7()`: time-ordered UUID v7
+pub fn uuid_v7(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 0)?;
+
+    Ok(Uuid::now_v7().to_string())
+}
+
+/// `uuid_v5(namespace, name)`: deterministic UUID v5. The namespace is
+/// `dns`, `url`, `oid`, `x500` or any UUID
+pub fn uuid_v5(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 2)?;
+    validations::args_max_len(args, 2)?;
+
+    let namespace = match args[0].trim().to_lowercase().as_str() {
+        "dns" => Uuid::NAMESPACE_DNS,
+        "url" => Uuid::NAMESPACE_URL,
+        "oid" => Uuid::NAMESPACE_OID,
+        "x500" => Uuid::NAMESPACE_X500,
+        namespace => Uuid::parse_str(namespace).map_err(|_| {
+            function::Error::InvalidArgument(format!(
+                "uuid_v5 namespace must be dns, url, oid, x500 or a UUID, found {}",
+                args[0]
+            ))
+        })?,
+    };
+
+    Ok(Uuid::new_v5(&namespace, args[1].as_bytes()).to_string())
+}
+
+/// `ulid()`: lexicographically sortable identifier
+pub fn ulid(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 0)?;
+
+    Ok(ulid::Ulid::new().to_string())
+}
+
+/// `nanoid([size[, alphabet]])`: URL friendly identifier, 21 characters by
+/// default
+pub fn nanoid(args: &[String]) -> FuncResult {
+    validations::args_max_len(args, 2)?;
+
+    let size = match args.first() {
+        Some(size) => size
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| function::Error::InvalidType(size.to_owned()))?,
+        None => 21,
+    };
+
+    let alphabet = match args.get(1) {
+        Some(alphabet) if !alphabet.is_empty() => alphabet.chars().collect(),
+        Some(_) => {
+            return Err(function::Error::InvalidArgument(
+                "nanoid alphabet cannot be empty".to_owned(),
+            ))
+        }
+        None => nanoid::alphabet::SAFE.to_vec(),
+    };
+
+    Ok(nanoid::format(nanoid::rngs::default, &alphabet, size))
+}
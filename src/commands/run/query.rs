@@ -22,7 +22,8 @@ pub async fn resolve_query_placeholders(
     Ok(result)
 }
 
-async fn execute_query_for_value(
+/// Executes `sql` and returns the first column of the first row as text
+pub async fn execute_query_for_value(
     sql: &str,
     database_url: &str,
 ) -> Result<String, String> {
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use fake::faker::chrono::raw::DateTimeBetween;
+use fake::locales::EN;
+use fake::Fake;
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+
+use super::datetime::{self, DATE_FORMAT, TIMESTAMP_FORMAT};
+
+/// `rand_int(min, max)`: random integer in the inclusive range
+pub fn rand_int(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 2)?;
+    validations::args_max_len(args, 2)?;
+
+    let min = parse_arg::<i64>(&args[0])?;
+    let max = parse_arg::<i64>(&args[1])?;
+
+    if min > max {
+        return Err(function::Error::RuntimeError(format!(
+            "rand_int: {min} is greater than {max}"
+        )));
+    }
+
+    Ok((min..=max).fake::<i64>().to_string())
+}
+
+/// `rand_float(min, max[, decimals])`: random float in the range, optionally
+/// rounded to a number of decimals
+pub fn rand_float(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 2)?;
+    validations::args_max_len(args, 3)?;
+
+    let min = parse_arg::<f64>(&args[0])?;
+    let max = parse_arg::<f64>(&args[1])?;
+    let decimals = args.get(2).map(|d| parse_arg::<usize>(d)).transpose()?;
+
+    if min > max {
+        return Err(function::Error::RuntimeError(format!(
+            "rand_float: {min} is greater than {max}"
+        )));
+    }
+
+    let value = if min == max {
+        min
+    } else {
+        (min..max).fake::<f64>()
+    };
+
+    Ok(match decimals {
+        Some(decimals) => format!("{value:.decimals$}"),
+        None => value.to_string(),
+    })
+}
+
+/// `date_between(start, end)`: random date between both dates, inclusive
+pub fn date_between(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 2)?;
+    validations::args_max_len(args, 2)?;
+
+    let start = parse_timestamp(&args[0])?.date();
+    let end = parse_timestamp(&args[1])?.date();
+
+    if start > end {
+        return Err(function::Error::RuntimeError(format!(
+            "date_between: {start} is after {end}"
+        )));
+    }
+
+    let start = start.and_time(NaiveTime::MIN).and_utc();
+    let end = end.and_hms_opt(23, 59, 59).unwrap_or_default().and_utc();
+
+    Ok(datetime_between(start, end).format(DATE_FORMAT).to_string())
+}
+
+/// `datetime_past(duration)`: random timestamp within the last `duration`,
+/// e.g. `datetime_past(30d)`
+pub fn datetime_past(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 1)?;
+
+    let duration = parse_duration(&args[0])?;
+
+    if duration < Duration::zero() {
+        return Err(function::Error::RuntimeError(format!(
+            "datetime_past: duration must be positive, found {}",
+            args[0]
+        )));
+    }
+
+    let now = datetime::now().and_utc();
+
+    Ok(datetime_between(now - duration, now)
+        .format(TIMESTAMP_FORMAT)
+        .to_string())
+}
+
+fn datetime_between(start: DateTime<Utc>, end: DateTime<Utc>) -> DateTime<Utc> {
+    // The faker picks whole minutes and cannot sample an empty range
+    if end - start < Duration::minutes(1) {
+        return start;
+    }
+
+    DateTimeBetween(EN, start, end).fake()
+}
+
+fn parse_arg<T: FromStr>(arg: &str) -> Result<T, function::Error> {
+    arg.trim()
+        .parse::<T>()
+        .map_err(|_| function::Error::InvalidType(arg.to_owned()))
+}
+
+fn parse_timestamp(
+    arg: &str,
+) -> Result<chrono::NaiveDateTime, function::Error> {
+    datetime::parse_timestamp(arg).map_err(function::Error::InvalidArgument)
+}
+
+fn parse_duration(arg: &str) -> Result<Duration, function::Error> {
+    datetime::parse_duration(arg).map_err(function::Error::InvalidArgument)
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use super::entry::Seeder;
+use super::seeder_format;
+
+/// Seeders of the `#![requires(...)]` of the seeder `name`, without their
+/// extension
+pub fn seeder_requirements(
+    root: &Path,
+    name: &str,
+) -> Result<Vec<String>, String> {
+    Ok(Seeder::read(root, name)?
+        .requires
+        .iter()
+        .map(|required| seeder_format::seeder_name(required).to_owned())
+        .collect())
+}
+
+/// Seeders to run for `name`: the seeders it requires, recursively and each
+/// once, followed by `name`
+pub fn plan(root: &Path, name: &str) -> Result<Vec<String>, String> {
+    let mut plan = Vec::new();
+    visit(root, name, &mut vec![], &mut plan)?;
+
+    Ok(plan)
+}
+
+/// Adds the requirements of `name` and then `name` to `plan`, `requiring`
+/// holds the seeders being visited to find cycles
+fn visit(
+    root: &Path,
+    name: &str,
+    requiring: &mut Vec<String>,
+    plan: &mut Vec<String>,
+) -> Result<(), String> {
+    if plan.iter().any(|planned| planned == name) {
+        return Ok(());
+    }
+
+    if let Some(start) = requiring.iter().position(|visited| visited == name) {
+        let mut chain = requiring[start..].to_vec();
+        chain.push(name.to_owned());
+
+        return Err(format!("Requirement cycle: {}", chain.join(" -> ")));
+    }
+
+    requiring.push(name.to_owned());
+
+    for required in seeder_requirements(root, name)? {
+        if !seeder_format::seeder_path(root, &required).is_file() {
+            return Err(format!(
+                "{name} requires {required}, which is not a seeder"
+            ));
+        }
+
+        visit(root, &required, requiring, plan)?;
+    }
+
+    requiring.pop();
+    plan.push(name.to_owned());
+
+    Ok(())
+}
+
+/// Orders `names` so every seeder runs after the seeders of `names` that it
+/// requires, keeping the given order otherwise. The requirements of the
+/// seeders that cannot be read are ignored, so running them reports the
+/// error.
+pub fn order(root: &Path, names: Vec<String>) -> Result<Vec<String>, String> {
+    let requirements = names
+        .iter()
+        .map(|name| seeder_requirements(root, name).unwrap_or_default())
+        .collect::<Vec<_>>();
+
+    let mut ordered: Vec<String> = Vec::with_capacity(names.len());
+    let mut placed = vec![false; names.len()];
+
+    while ordered.len() < names.len() {
+        let next = (0..names.len()).find(|&index| {
+            !placed[index]
+                && requirements[index].iter().all(|required| {
+                    !names.contains(required) || ordered.contains(required)
+                })
+        });
+
+        let Some(index) = next else {
+            let remaining = (0..names.len())
+                .filter(|&index| !placed[index])
+                .map(|index| names[index].as_str())
+                .collect::<Vec<_>>();
+
+            return Err(format!(
+                "Requirement cycle between {}",
+                remaining.join(", ")
+            ));
+        };
+
+        placed[index] = true;
+        ordered.push(names[index].clone());
+    }
+
+    Ok(ordered)
+}
@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use grow_core::SqlValue;
+use serde_json::{Map, Value};
+
+use super::data_file::{json_value, DataFile};
+use super::entry::{self, Entry, EntryOptions, Seeder};
+use super::sql::split_statements;
+use super::variables;
+
+/// Languages of a seeder file, all of them are parsed to the same entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeederFormat {
+    Ron,
+    Yaml,
+    Json,
+    Toml,
+    /// Raw SQL, each statement is an entry
+    Sql,
+}
+
+impl SeederFormat {
+    /// Extensions of the seeder files, in the order a name is looked up
+    pub const EXTENSIONS: [&'static str; 6] =
+        ["ron", "yaml", "yml", "json", "toml", "sql"];
+
+    pub fn from_path(path: &Path) -> Option<SeederFormat> {
+        match path.extension()?.to_str()? {
+            "ron" => Some(SeederFormat::Ron),
+            "yaml" | "yml" => Some(SeederFormat::Yaml),
+            "json" => Some(SeederFormat::Json),
+            "toml" => Some(SeederFormat::Toml),
+            "sql" => Some(SeederFormat::Sql),
+            _ => None,
+        }
+    }
+
+    /// Whether the file at `path` holds a seeder. JSON and YAML files whose
+    /// top level is not a map are data, such as the rows of a `#[json]`
+    /// entry. Files that cannot be read are kept, to fail when they run.
+    pub fn is_seeder(self, path: &Path) -> bool {
+        match self {
+            SeederFormat::Json => {
+                let Ok(file) = File::open(path) else {
+                    return true;
+                };
+
+                BufReader::new(file)
+                    .bytes()
+                    .map_while(Result::ok)
+                    .find(|byte| !byte.is_ascii_whitespace())
+                    .is_some_and(|byte| byte == b'{')
+            }
+            SeederFormat::Yaml => std::fs::read_to_string(path)
+                .ok()
+                .and_then(|content| {
+                    serde_yaml::from_str::<serde_yaml::Value>(&content).ok()
+                })
+                .is_none_or(|document| document.is_mapping()),
+            SeederFormat::Ron | SeederFormat::Toml | SeederFormat::Sql => true,
+        }
+    }
+
+    /// Parses the content of a seeder file written in this format
+    pub fn parse(self, content: String) -> Result<Seeder, String> {
+        let document = match self {
+            SeederFormat::Ron => return Seeder::parse(content),
+            SeederFormat::Sql => {
+                return Ok(Seeder::from(
+                    split_statements(&content)
+                        .into_iter()
+                        .map(|statement| Entry::Sql { statement })
+                        .collect::<Vec<_>>(),
+                ))
+            }
+            SeederFormat::Yaml => serde_yaml::from_str(&content)
+                .map_err(|err| format!("Cannot parse seeder: {err}"))?,
+            SeederFormat::Json => serde_json::from_str(&content)
+                .map_err(|err| format!("Cannot parse seeder: {err}"))?,
+            SeederFormat::Toml => toml::from_str(&content)
+                .map(toml_to_json)
+                .map_err(|err| format!("Cannot parse seeder: {err}"))?,
+        };
+
+        seeder_from_document(document)
+    }
+}
+
+/// Name of a seeder without the extension of its format
+pub fn seeder_name(file_name: &str) -> &str {
+    Path::new(file_name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .filter(|extension| SeederFormat::EXTENSIONS.contains(extension))
+        .map_or(file_name, |extension| {
+            &file_name[..file_name.len() - extension.len() - 1]
+        })
+}
+
+/// File of a seeder given its name, with or without extension. Without one
+/// the first existing format is used, RON when there is none.
+pub fn seeder_path(folder: &Path, file_name: &str) -> PathBuf {
+    if seeder_name(file_name) != file_name {
+        return folder.join(file_name);
+    }
+
+    SeederFormat::EXTENSIONS
+        .iter()
+        .map(|extension| folder.join(format!("{file_name}.{extension}")))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| folder.join(format!("{file_name}.ron")))
+}
+
+/// Files in a folder or named with a leading `_` are partials, which are
+/// only run through `#![include(...)]`. `path` is relative to the seeders
+/// folder.
+pub fn is_partial(path: &Path) -> bool {
+    path.components().any(|component| {
+        component.as_os_str().to_string_lossy().starts_with('_')
+    })
+}
+
+/// TOML dates are kept as the text of the date
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(text) => Value::String(text),
+        toml::Value::Integer(int) => Value::from(int),
+        toml::Value::Float(float) => Value::from(float),
+        toml::Value::Boolean(boolean) => Value::Bool(boolean),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(values) => {
+            Value::Array(values.into_iter().map(toml_to_json).collect())
+        }
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Seeder of a map of tables. The `grow` key holds the file attributes,
+/// `vars`, `include`, `templates`, `env`, `requires` and `connection`, so any
+/// other name is a table.
+fn seeder_from_document(document: Value) -> Result<Seeder, String> {
+    let Value::Object(mut tables) = document else {
+        return Err("Expect map".to_owned());
+    };
+
+    let mut seeder = Seeder::default();
+
+    if let Some(attributes) = tables.shift_remove("grow") {
+        let Value::Object(attributes) = attributes else {
+            return Err("Expect map of file attributes in grow".to_owned());
+        };
+
+        for (key, value) in attributes {
+            apply_file_attribute(&mut seeder, &key, value)?;
+        }
+    }
+
+    for (key, value) in tables {
+        seeder.entries.push(entry_from_value(&key, value)?);
+    }
+
+    Ok(seeder)
+}
+
+fn apply_file_attribute(
+    seeder: &mut Seeder,
+    key: &str,
+    value: Value,
+) -> Result<(), String> {
+    match key {
+        "vars" => seeder.vars = vars_from_value(value)?,
+        "include" => {
+            seeder.includes =
+                strings_from_value(value, "seeder paths in include")?
+        }
+        "templates" => seeder.templates = templates_from_value(value)?,
+        "requires" => {
+            seeder.requires =
+                strings_from_value(value, "seeder names in requires")?
+        }
+        "env" => {
+            seeder.environments =
+                strings_from_value(value, "environment names in env")?
+        }
+        "connection" => {
+            let Value::String(name) = value else {
+                return Err("Expect connection name in connection".to_owned());
+            };
+            seeder.connection = Some(name);
+        }
+        _ => return Err(format!("Unknown file attribute `{key}` in grow")),
+    }
+
+    Ok(())
+}
+
+fn vars_from_value(value: Value) -> Result<Vec<(String, String)>, String> {
+    let Value::Object(vars) = value else {
+        return Err("Expect `name: value` pairs in vars".to_owned());
+    };
+
+    vars.into_iter()
+        .map(|(name, value)| {
+            variables::validate_name(&name)?;
+
+            match value {
+                Value::String(_) | Value::Number(_) | Value::Bool(_) => {
+                    Ok((name, json_value(value).to_string()))
+                }
+                _ => Err(format!(
+                    "Expected primitive as value in variable `{name}`"
+                )),
+            }
+        })
+        .collect()
+}
+
+/// One string or a list of them, as the seeder paths of `include`
+fn strings_from_value(
+    value: Value,
+    expected: &str,
+) -> Result<Vec<String>, String> {
+    let values = match value {
+        Value::Array(values) => values,
+        value => vec![value],
+    };
+
+    values
+        .into_iter()
+        .map(|value| match value {
+            Value::String(text) => Ok(text),
+            _ => Err(format!("Expect {expected}")),
+        })
+        .collect()
+}
+
+fn templates_from_value(
+    value: Value,
+) -> Result<BTreeMap<String, BTreeMap<String, SqlValue>>, String> {
+    let Value::Object(templates) = value else {
+        return Err("Expect `name: fields` pairs in templates".to_owned());
+    };
+
+    templates
+        .into_iter()
+        .map(|(name, fields)| {
+            let fields = row_from_value(fields, &format!("template `{name}`"))?;
+            Ok((name, fields))
+        })
+        .collect()
+}
+
+/// Entry of a table, either a list of rows or a map with the attributes of
+/// RON, as `repeat`, `schema` or `reset_sequence`, and its `fields`,
+/// `values`, `csv` or `json`
+fn entry_from_value(table_name: &str, value: Value) -> Result<Entry, String> {
+    let mut attributes = match value {
+        Value::Array(values) => {
+            return Ok(Entry::Static {
+                table_name: table_name.to_owned(),
+                values: rows_from_values(values, table_name)?,
+                options: EntryOptions::default(),
+            })
+        }
+        Value::Object(attributes) => attributes,
+        _ => {
+            return Err(format!("Expect list or map as value in {table_name}"))
+        }
+    };
+
+    let repeat =
+        take_attribute(&mut attributes, "repeat", table_name, |value| {
+            value.as_u64().map(|count| count as usize)
+        })?;
+    let schema = take_string(&mut attributes, "schema", table_name)?;
+    let locale = take_string(&mut attributes, "locale", table_name)?;
+    let unique = take_unique(&mut attributes, table_name)?;
+    let render =
+        take_attribute(&mut attributes, "render", table_name, Value::as_bool)?;
+    let reset_sequence = take_attribute(
+        &mut attributes,
+        "reset_sequence",
+        table_name,
+        Value::as_bool,
+    )?;
+    let csv = take_string(&mut attributes, "csv", table_name)?;
+    let json = take_string(&mut attributes, "json", table_name)?;
+    let template = take_string(&mut attributes, "template", table_name)?;
+    let fields = attributes.remove("fields");
+    let values = attributes.remove("values");
+
+    if let Some(key) = attributes.keys().next() {
+        return Err(format!("Unknown attribute `{key}` in {table_name}"));
+    }
+
+    let options = EntryOptions {
+        unique,
+        locale,
+        keep_sequence: reset_sequence == Some(false),
+        template,
+    };
+    let table_name = entry::table_name_in_schema(table_name, schema);
+
+    let source = match (csv, json) {
+        (Some(_), Some(_)) => {
+            return Err(format!(
+                "`csv` cannot be used with `json` in {table_name}"
+            ))
+        }
+        (csv, json) => csv
+            .map(|path| DataFile::Csv(PathBuf::from(path)))
+            .or(json.map(|path| DataFile::Json(PathBuf::from(path)))),
+    };
+
+    match (source, fields, values) {
+        (Some(source), None, None) => Entry::from_file(
+            table_name,
+            source,
+            repeat,
+            render.unwrap_or_default(),
+            options,
+        ),
+        (None, Some(fields), None) => {
+            let Some(count) = repeat else {
+                return Err(format!("`fields` need `repeat` in {table_name}"));
+            };
+
+            Ok(Entry::Repeat {
+                count,
+                fields: row_from_value(fields, &table_name)?,
+                table_name,
+                options,
+            })
+        }
+        (None, None, Some(Value::Array(values))) if repeat.is_none() => {
+            Ok(Entry::Static {
+                values: rows_from_values(values, &table_name)?,
+                table_name,
+                options,
+            })
+        }
+        _ => Err(format!(
+            "Expect `fields` with `repeat`, `values`, `csv` or `json` in {table_name}"
+        )),
+    }
+}
+
+fn rows_from_values(
+    values: Vec<Value>,
+    table_name: &str,
+) -> Result<Vec<BTreeMap<String, SqlValue>>, String> {
+    values
+        .into_iter()
+        .map(|value| row_from_value(value, table_name))
+        .collect()
+}
+
+/// Fields of a row, nested maps and lists are JSON values
+fn row_from_value(
+    value: Value,
+    table_name: &str,
+) -> Result<BTreeMap<String, SqlValue>, String> {
+    match value {
+        Value::Object(fields) => Ok(fields
+            .into_iter()
+            .map(|(key, value)| (key, json_value(value)))
+            .collect()),
+        _ => Err(format!("Expect map as row in {table_name}")),
+    }
+}
+
+fn take_attribute<T>(
+    attributes: &mut Map<String, Value>,
+    name: &str,
+    table_name: &str,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Result<Option<T>, String> {
+    attributes
+        .remove(name)
+        .map(|value| {
+            convert(&value).ok_or_else(|| {
+                format!("Invalid `{name}` in {table_name}: {value}")
+            })
+        })
+        .transpose()
+}
+
+fn take_string(
+    attributes: &mut Map<String, Value>,
+    name: &str,
+    table_name: &str,
+) -> Result<Option<String>, String> {
+    take_attribute(attributes, name, table_name, |value| {
+        value.as_str().map(str::to_owned)
+    })
+}
+
+/// Fields of `unique`, a single string is accepted too
+fn take_unique(
+    attributes: &mut Map<String, Value>,
+    table_name: &str,
+) -> Result<Vec<String>, String> {
+    let unique =
+        take_attribute(
+            attributes,
+            "unique",
+            table_name,
+            |value| match value {
+                Value::String(column) => Some(vec![column.clone()]),
+                Value::Array(columns) => columns
+                    .iter()
+                    .map(|column| column.as_str().map(str::to_owned))
+                    .collect(),
+                _ => None,
+            },
+        )?;
+
+    Ok(unique.unwrap_or_default())
+}
@@ -6,17 +6,28 @@ use std::str::FromStr;
 pub struct SeederTracker {
     scheme: SchemeDriver,
     database_url: String,
+    /// Table of the executed seeders, `_grow_seeders` by default
+    table: String,
 }
 
 impl SeederTracker {
-    pub fn new(database_url: String) -> Result<Self, Box<dyn Error>> {
+    pub fn new(
+        database_url: String,
+        table: String,
+    ) -> Result<Self, Box<dyn Error>> {
         let scheme = SchemeDriver::from_str(&database_url)?;
         Ok(Self {
             scheme,
             database_url,
+            table,
         })
     }
 
+    /// URL of the database of the tracking table
+    pub fn database_url(&self) -> &str {
+        &self.database_url
+    }
+
     /// Detects the specific database type from the URL
     fn get_database_type(&self) -> &str {
         if let Some((scheme, _)) = self.database_url.split_once("://") {
@@ -28,54 +39,67 @@ impl SeederTracker {
 
     /// Ensures the seeds table exists in the database
     pub async fn ensure_seeds_table(&self) -> Result<(), Box<dyn Error>> {
+        let table = &self.table;
         let create_table_sql = match self.scheme {
             SchemeDriver::Mock => {
-                "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 timestamp INTEGER NOT NULL,
                 name TEXT NOT NULL UNIQUE
             )"
+                )
             }
             #[cfg(feature = "libsql")]
             SchemeDriver::Libsql => {
-                "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 timestamp INTEGER NOT NULL,
                 name TEXT NOT NULL UNIQUE
             )"
+                )
             }
             #[cfg(feature = "sqlx")]
             SchemeDriver::Sqlx => {
                 // Generate database-specific SQL
                 match self.get_database_type() {
                     "postgres" => {
-                        "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                        format!(
+                            "CREATE TABLE IF NOT EXISTS {table} (
                             id SERIAL PRIMARY KEY,
                             timestamp BIGINT NOT NULL,
                             name TEXT NOT NULL UNIQUE
                         )"
+                        )
                     }
                     "mysql" => {
-                        "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                        format!(
+                            "CREATE TABLE IF NOT EXISTS {table} (
                             id INT AUTO_INCREMENT PRIMARY KEY,
                             timestamp BIGINT NOT NULL,
                             name VARCHAR(255) NOT NULL UNIQUE
                         )"
+                        )
                     }
                     "sqlite" => {
-                        "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                        format!(
+                            "CREATE TABLE IF NOT EXISTS {table} (
                             id INTEGER PRIMARY KEY AUTOINCREMENT,
                             timestamp INTEGER NOT NULL,
                             name TEXT NOT NULL UNIQUE
                         )"
+                        )
                     }
                     _ => {
                         // Fallback to PostgreSQL syntax for unknown databases
-                        "CREATE TABLE IF NOT EXISTS _grow_seeders (
+                        format!(
+                            "CREATE TABLE IF NOT EXISTS {table} (
                             id SERIAL PRIMARY KEY,
                             timestamp BIGINT NOT NULL,
                             name TEXT NOT NULL UNIQUE
                         )"
+                        )
                     }
                 }
             }
@@ -88,15 +112,15 @@ impl SeederTracker {
 
         match self.scheme {
             SchemeDriver::Mock => {
-                println!("{}", create_table_sql);
+                println!("{create_table_sql}");
             }
             #[cfg(feature = "libsql")]
             SchemeDriver::Libsql => {
-                self.execute_libsql_query(create_table_sql, vec![]).await?;
+                self.execute_libsql_query(&create_table_sql, vec![]).await?;
             }
             #[cfg(feature = "sqlx")]
             SchemeDriver::Sqlx => {
-                self.execute_sqlx_query(create_table_sql, vec![]).await?;
+                self.execute_sqlx_query(&create_table_sql, vec![]).await?;
             }
             #[cfg(feature = "surrealdb")]
             SchemeDriver::Surrealdb => {
@@ -113,7 +137,8 @@ impl SeederTracker {
         &self,
         seeder_name: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let query = "SELECT COUNT(*) FROM _grow_seeders WHERE name = ?";
+        let query =
+            format!("SELECT COUNT(*) FROM {} WHERE name = ?", self.table);
 
         match self.scheme {
             SchemeDriver::Mock => {
@@ -137,7 +162,10 @@ impl SeederTracker {
         seeder_name: &str,
         timestamp: i64,
     ) -> Result<(), Box<dyn Error>> {
-        let query = "INSERT INTO _grow_seeders (timestamp, name) VALUES (?, ?)";
+        let query = format!(
+            "INSERT INTO {} (timestamp, name) VALUES (?, ?)",
+            self.table
+        );
 
         match self.scheme {
             SchemeDriver::Mock => {
@@ -149,7 +177,7 @@ impl SeederTracker {
             #[cfg(feature = "libsql")]
             SchemeDriver::Libsql => {
                 self.execute_libsql_query(
-                    query,
+                    &query,
                     vec![
                         SqlValue::Integer(timestamp),
                         SqlValue::Text(seeder_name.to_string()),
@@ -160,11 +188,13 @@ impl SeederTracker {
             #[cfg(feature = "sqlx")]
             SchemeDriver::Sqlx => {
                 // Update query syntax for SQLx (use $1, $2 instead of ?)
-                let sqlx_query =
-                    "INSERT INTO _grow_seeders (timestamp, name) VALUES ($1, $2)";
+                let sqlx_query = format!(
+                    "INSERT INTO {} (timestamp, name) VALUES ($1, $2)",
+                    self.table
+                );
                 grow_sqlx::execute_query_with_params(
                     self.database_url.clone(),
-                    sqlx_query,
+                    &sqlx_query,
                     timestamp,
                     seeder_name,
                 )
@@ -195,10 +225,11 @@ impl SeederTracker {
         &self,
         seeder_name: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let query = "SELECT COUNT(*) FROM _grow_seeders WHERE name = ?";
+        let query =
+            format!("SELECT COUNT(*) FROM {} WHERE name = ?", self.table);
         let count = grow_libsql::query_single_int(
             self.database_url.clone(),
-            query,
+            &query,
             vec![SqlValue::Text(seeder_name.to_string())],
         )
         .await
@@ -223,10 +254,11 @@ impl SeederTracker {
         &self,
         seeder_name: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let query = "SELECT COUNT(*) FROM _grow_seeders WHERE name = $1";
+        let query =
+            format!("SELECT COUNT(*) FROM {} WHERE name = $1", self.table);
         let count = grow_sqlx::query_single_int(
             self.database_url.clone(),
-            query,
+            &query,
             seeder_name,
         )
         .await
@@ -254,8 +286,8 @@ impl SeederTracker {
         // For SurrealDB, we'll use the grow_surrealdb crate's functionality
         // This is a simplified implementation - the actual implementation should use grow_surrealdb
         println!(
-            "INSERT INTO _grow_seeders {{ timestamp: {}, name: '{}' }}",
-            timestamp, _seeder_name
+            "INSERT INTO {} {{ timestamp: {}, name: '{}' }}",
+            self.table, timestamp, _seeder_name
         );
         Ok(())
     }
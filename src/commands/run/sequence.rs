@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+
+use super::drivers::SchemeDriver;
+use super::query;
+
+/// Next value of every named sequence, shared by all the seeders of a run
+static SEQUENCES: Mutex<BTreeMap<String, i64>> = Mutex::new(BTreeMap::new());
+
+/// `seq(name[, start[, "table.column"]])`: next value of a named sequence,
+/// `start` (1 by default) on the first call. With a column, the sequence
+/// resumes after its current maximum, see [`resume_sequences`].
+pub fn seq(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 3)?;
+
+    let name = args[0].trim();
+    if name.is_empty() {
+        return Err(function::Error::InvalidArgument(
+            "seq: the sequence needs a name".to_owned(),
+        ));
+    }
+
+    let start = match args.get(1) {
+        Some(start) => parse_start(start)?,
+        None => 1,
+    };
+
+    let mut sequences = SEQUENCES.lock().map_err(|_| {
+        function::Error::RuntimeError("seq: sequences are poisoned".to_owned())
+    })?;
+
+    let next = sequences.entry(name.to_owned()).or_insert(start);
+    let value = *next;
+    *next = value.checked_add(1).ok_or_else(|| {
+        function::Error::RuntimeError(format!("seq: {name} overflowed"))
+    })?;
+
+    Ok(value.to_string())
+}
+
+/// Starts the sequences of `text` that resume from a column, e.g.
+/// `{seq(order_number, 1000, "orders.number")}`, after the column's
+/// `MAX` in the database. Each sequence queries the database once per run.
+pub async fn resume_sequences(
+    text: &str,
+    database_url: &str,
+) -> Result<(), String> {
+    for args in extract_seq_calls(text) {
+        let [name, start, column] = args.as_slice() else {
+            continue;
+        };
+
+        let name = unquote(name);
+        let is_started = SEQUENCES
+            .lock()
+            .map_err(|_| "Sequences are poisoned".to_owned())?
+            .contains_key(name);
+
+        if is_started {
+            continue;
+        }
+
+        let start = parse_start(unquote(start))
+            .map_err(|_| format!("seq: invalid start of {name}: {start}"))?;
+
+        let scheme = SchemeDriver::from_str(database_url)?;
+
+        // The mock driver has no data to resume from
+        let value = if matches!(scheme, SchemeDriver::Mock) {
+            start
+        } else {
+            let sql = max_query(&scheme, unquote(column), start)?;
+            let max =
+                query::execute_query_for_value(&sql, database_url).await?;
+
+            max.trim()
+                .parse::<i64>()
+                .map_err(|_| {
+                    format!("seq: {column} has a non integer maximum: {max}")
+                })?
+                .saturating_add(1)
+                .max(start)
+        };
+
+        SEQUENCES
+            .lock()
+            .map_err(|_| "Sequences are poisoned".to_owned())?
+            .insert(name.to_owned(), value);
+    }
+
+    Ok(())
+}
+
+/// Query of the maximum of `table.column`, `start - 1` when the table is
+/// empty. The names are quoted as the driver quotes them on insert.
+fn max_query(
+    scheme: &SchemeDriver,
+    column: &str,
+    start: i64,
+) -> Result<String, String> {
+    let Some((table, column)) = column.rsplit_once('.') else {
+        return Err(format!(
+            "seq: expected \"table.column\" to resume from, found {column}"
+        ));
+    };
+
+    let (table, column) = match scheme {
+        #[cfg(feature = "sqlx")]
+        SchemeDriver::Sqlx => (
+            grow_sqlx::escape_table_name(table),
+            grow_sqlx::escape_column_name(column),
+        ),
+        #[cfg(feature = "libsql")]
+        SchemeDriver::Libsql => (
+            grow_libsql::escape_table_name(table),
+            grow_libsql::escape_column_name(column),
+        ),
+        _ => (table.to_owned(), column.to_owned()),
+    };
+
+    Ok(format!(
+        "SELECT COALESCE(MAX({column}), {}) FROM {table}",
+        start.saturating_sub(1)
+    ))
+}
+
+fn parse_start(start: &str) -> Result<i64, function::Error> {
+    start
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| function::Error::InvalidType(start.to_owned()))
+}
+
+fn unquote(text: &str) -> &str {
+    let text = text.trim();
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+/// Arguments of every `seq(...)` call in `text`, split on top level commas
+fn extract_seq_calls(text: &str) -> Vec<Vec<&str>> {
+    let mut calls = vec![];
+    let mut search_from = 0;
+
+    while let Some(relative) = text[search_from..].find("seq(") {
+        let start = search_from + relative;
+        let arguments_start = start + "seq(".len();
+        search_from = arguments_start;
+
+        let is_name_part = text[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if is_name_part {
+            continue;
+        }
+
+        let (mut depth, mut in_string, mut escaped) = (1usize, false, false);
+        let mut arguments = vec![];
+        let mut argument_start = arguments_start;
+
+        for (i, c) in text[arguments_start..].char_indices() {
+            let i = arguments_start + i;
+
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match c {
+                '"' => in_string = true,
+                '(' => depth += 1,
+                ',' if depth == 1 => {
+                    arguments.push(&text[argument_start..i]);
+                    argument_start = i + 1;
+                }
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        arguments.push(&text[argument_start..i]);
+                        calls.push(arguments);
+                        search_from = i + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    calls
+}
@@ -0,0 +1,75 @@
+/// Splits SQL into its statements at the `;` that are not inside a string,
+/// a quoted identifier, a dollar-quoted body or a comment. Comments are
+/// removed, so they can hold `{` without being read as templates.
+pub fn split_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut statement = String::new();
+    let mut rest = sql;
+
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("--") {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+        } else if rest.starts_with("/*") {
+            rest = rest.find("*/").map_or("", |end| &rest[end + 2..]);
+            statement.push(' ');
+        } else if c == ';' {
+            push_statement(&mut statements, &statement);
+            statement.clear();
+            rest = &rest[1..];
+        } else if c == '\'' || c == '"' || c == '`' {
+            let end = quoted_end(rest, c);
+            statement.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else if let Some(tag) = dollar_tag(rest) {
+            let end = rest[tag.len()..]
+                .find(tag)
+                .map_or(rest.len(), |end| tag.len() * 2 + end);
+            statement.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else {
+            statement.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    push_statement(&mut statements, &statement);
+    statements
+}
+
+fn push_statement(statements: &mut Vec<String>, statement: &str) {
+    let statement = statement.trim();
+
+    if !statement.is_empty() {
+        statements.push(statement.to_owned());
+    }
+}
+
+/// Length of the quoted text at the start of `text`, a doubled quote is an
+/// escaped one
+fn quoted_end(text: &str, quote: char) -> usize {
+    let mut chars = text.char_indices().skip(1).peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == quote {
+            if chars.peek().is_some_and(|(_, next)| *next == quote) {
+                chars.next();
+            } else {
+                return i + 1;
+            }
+        }
+    }
+
+    text.len()
+}
+
+/// Tag of a Postgres dollar-quoted body, as `$$` or `$body$`
+fn dollar_tag(text: &str) -> Option<&str> {
+    let inner = text.strip_prefix('$')?;
+    let end = inner.find('$')?;
+
+    inner[..end]
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        .then(|| &text[..end + 2])
+        .filter(|tag| !tag[1..].starts_with(|c: char| c.is_ascii_digit()))
+}
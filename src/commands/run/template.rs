@@ -1,11 +1,45 @@
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 
+use futures::channel::mpsc;
+use futures::SinkExt;
 use srtemplate::SrTemplate;
 
-use super::entry::Entry;
-use grow_core::SqlValue;
+use super::entry::{Entry, EntryOptions, Seeder};
+use super::expression;
+use super::unique;
+use grow_core::{SqlValue, Statement};
 
-pub type RenderedTable = Vec<Vec<(String, SqlValue)>>;
+/// Native type of the value returned by a template function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Integer,
+    Float,
+    Date,
+    Timestamp,
+    Uuid,
+}
+
+/// Template functions whose result keeps its native type when the call is
+/// the whole value of a field, e.g. `"{rand_int(1, 10)}"`
+const TYPED_FUNCTIONS: &[(&str, ValueKind)] = &[
+    ("rand_int", ValueKind::Integer),
+    ("seq", ValueKind::Integer),
+    ("rand_float", ValueKind::Float),
+    ("date_between", ValueKind::Date),
+    ("datetime_past", ValueKind::Timestamp),
+    ("now", ValueKind::Timestamp),
+    ("today", ValueKind::Date),
+    ("now_offset", ValueKind::Timestamp),
+    ("timestamp_add", ValueKind::Timestamp),
+    ("uuid", ValueKind::Uuid),
+    ("uuid_v5", ValueKind::Uuid),
+    ("uuid_v7", ValueKind::Uuid),
+];
+
+/// Functions whose first argument is a name, so it can be written without
+/// quotes as in `{env(APP_DOMAIN)}`
+const NAME_ARGUMENT_FUNCTIONS: &[&str] = &["env", "seq", "uuid_v5"];
 
 pub fn start<'a>() -> SrTemplate<'a> {
     let mut templating = SrTemplate::default();
@@ -14,111 +48,640 @@ pub fn start<'a>() -> SrTemplate<'a> {
     #[cfg(feature = "fake")]
     super::fake_generated::setup_faker_variables(&templating);
 
+    templating.add_function("env", super::variables::env);
+
+    for (name, value) in super::variables::overrides() {
+        templating.add_variable(name, &value);
+    }
+
     #[cfg(feature = "fake")]
     templating.add_function("fake", super::fake::fake);
 
+    templating.add_function("if", super::expression::if_then_else);
+    templating.add_function("choice", super::expression::choice);
+    templating.add_function("weighted", super::expression::weighted);
+    templating.add_function("seq", super::sequence::seq);
+
+    templating.add_function("bcrypt", super::hash::bcrypt);
+    templating.add_function("argon2", super::hash::argon2);
+    templating.add_function("sha256", super::hash::sha256);
+
+    #[cfg(feature = "fake")]
+    {
+        templating.add_function("rand_int", super::random::rand_int);
+        templating.add_function("rand_float", super::random::rand_float);
+        templating.add_function("date_between", super::random::date_between);
+        templating.add_function("datetime_past", super::random::datetime_past);
+    }
+
+    templating.add_function("now", super::clock::now);
+    templating.add_function("today", super::clock::today);
+    templating.add_function("now_offset", super::clock::now_offset);
+    templating.add_function("timestamp_add", super::clock::timestamp_add);
+    templating.add_function("format_date", super::clock::format_date);
+
+    templating.add_function("uuid", super::identifier::uuid);
+    templating.add_function("uuid_v5", super::identifier::uuid_v5);
+    templating.add_function("uuid_v7", super::identifier::uuid_v7);
+    templating.add_function("ulid", super::identifier::ulid);
+    templating.add_function("nanoid", super::identifier::nanoid);
+
     templating
 }
 
-pub async fn render_tables(
-    entries: Vec<Entry>,
+/// Renders `text`, accepting literals that srtemplate cannot read by itself.
+/// Blocks with operators, e.g. `{if(i % 10 == 0, "admin", "client")}`, are
+/// evaluated by [`expression::evaluate`](super::expression::evaluate), and
+/// blocks that are not templates, as JSON text, are kept as written.
+pub fn render(
+    templating: &SrTemplate,
+    text: &str,
+) -> Result<String, srtemplate::Error> {
+    let text = quote_bare_literals(text);
+    let blocks = expression_blocks(&text);
+
+    if blocks.iter().all(|(start, end)| {
+        let inner = &text[start + 1..end - 1];
+        expression::is_template(inner) && !expression::needs_evaluation(inner)
+    }) {
+        return templating.render(text);
+    }
+
+    let mut rendered = String::with_capacity(text.len());
+    let mut copied = 0;
+
+    for (start, end) in blocks {
+        rendered.push_str(&text[copied..start]);
+
+        let inner = &text[start + 1..end - 1];
+        if expression::needs_evaluation(inner) {
+            let value =
+                expression::evaluate(templating, inner).map_err(|err| {
+                    srtemplate::Error::Function(
+                        srtemplate::function::Error::RuntimeError(err),
+                    )
+                })?;
+            rendered.push_str(&value);
+        } else if expression::is_template(inner) {
+            rendered.push_str(&templating.render(&text[start..end])?);
+        } else {
+            rendered.push_str(&text[start..end]);
+        }
+
+        copied = end;
+    }
+
+    rendered.push_str(&text[copied..]);
+    Ok(rendered)
+}
+
+/// Byte ranges of the `{...}` blocks of `text`, braces included
+fn expression_blocks(text: &str) -> Vec<(usize, usize)> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let (mut in_string, mut escaped) = (false, false);
+
+    for (i, byte) in text.bytes().enumerate() {
+        match start {
+            None if byte == b'{' => start = Some(i),
+            None => {}
+            Some(_) if in_string => match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            },
+            Some(block_start) => match byte {
+                b'"' => in_string = true,
+                b'}' => {
+                    blocks.push((block_start, i + 1));
+                    start = None;
+                }
+                _ => {}
+            },
+        }
+    }
+
+    blocks
+}
+
+/// Converts the rendered value of a field to the native type of its
+/// template function, when the field is a single typed function call
+pub fn typed_value(template: &str, rendered: String) -> SqlValue {
+    let kind = sole_function_call(template).and_then(|name| {
+        TYPED_FUNCTIONS
+            .iter()
+            .find(|(function, _)| *function == name)
+            .map(|(_, kind)| *kind)
+    });
+
+    match kind {
+        Some(ValueKind::Integer) => match rendered.parse::<i64>() {
+            Ok(value) => SqlValue::Integer(value),
+            Err(_) => SqlValue::Text(rendered),
+        },
+        Some(ValueKind::Float) => match rendered.parse::<f64>() {
+            Ok(value) => SqlValue::Float(value),
+            Err(_) => SqlValue::Text(rendered),
+        },
+        Some(ValueKind::Date) => SqlValue::Date(rendered),
+        Some(ValueKind::Timestamp) => SqlValue::Timestamp(rendered),
+        Some(ValueKind::Uuid) => SqlValue::Uuid(rendered),
+        None => SqlValue::Text(rendered),
+    }
+}
+
+/// Returns the function name when `text` is exactly one `{name(...)}` call
+fn sole_function_call(text: &str) -> Option<&str> {
+    let inner = text.trim().strip_prefix('{')?;
+    let (name, args) = inner.split_once('(')?;
+    let name = name.trim();
+
+    if name.is_empty()
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    let (mut depth, mut in_string, mut escaped) = (1usize, false, false);
+
+    for (i, c) in args.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (args[i + 1..].trim() == "}").then_some(name);
+                }
+            }
+            '{' | '}' => return None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// srtemplate only reads unsigned numbers as arguments and takes bare words
+/// as variables, so signed numbers, durations (`-5`, `30d`, `-7d`) and the
+/// names given to [`NAME_ARGUMENT_FUNCTIONS`] are quoted before rendering
+fn quote_bare_literals(text: &str) -> Cow<'_, str> {
+    let bytes = text.as_bytes();
+    let mut quoted = String::new();
+    let mut copied = 0;
+    let (mut in_expr, mut in_string, mut escaped) = (false, false, false);
+    let mut previous = 0u8;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+
+        if !in_expr {
+            in_expr = byte == b'{';
+            previous = byte;
+            i += 1;
+            continue;
+        }
+
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            previous = byte;
+            i += 1;
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'}' => in_expr = false,
+            b'-' | b'0'..=b'9' if matches!(previous, b'(' | b',') => {
+                let end = literal_end(bytes, i);
+                let literal = &text[i..end];
+                let next =
+                    bytes[end..].iter().find(|b| !b.is_ascii_whitespace());
+
+                if needs_quotes(literal) && matches!(next, Some(b',' | b')')) {
+                    quoted.push_str(&text[copied..i]);
+                    quoted.push('"');
+                    quoted.push_str(literal);
+                    quoted.push('"');
+                    copied = end;
+                }
+
+                previous = bytes[end - 1];
+                i = end;
+                continue;
+            }
+            b'a'..=b'z' | b'A'..=b'Z' | b'_'
+                if previous == b'(' && takes_name_argument(&text[..i]) =>
+            {
+                let end = identifier_end(bytes, i);
+                let next =
+                    bytes[end..].iter().find(|b| !b.is_ascii_whitespace());
+
+                if matches!(next, Some(b',' | b')')) {
+                    quoted.push_str(&text[copied..i]);
+                    quoted.push('"');
+                    quoted.push_str(&text[i..end]);
+                    quoted.push('"');
+                    copied = end;
+                }
+
+                previous = bytes[end - 1];
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+
+        if !byte.is_ascii_whitespace() {
+            previous = byte;
+        }
+        i += 1;
+    }
+
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+
+    quoted.push_str(&text[copied..]);
+    Cow::Owned(quoted)
+}
+
+fn literal_end(bytes: &[u8], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < bytes.len()
+        && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'.')
+    {
+        end += 1;
+    }
+    end
+}
+
+fn identifier_end(bytes: &[u8], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < bytes.len()
+        && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_')
+    {
+        end += 1;
+    }
+    end
+}
+
+/// Whether `before` ends with the opening parenthesis of a function listed
+/// in [`NAME_ARGUMENT_FUNCTIONS`]
+fn takes_name_argument(before: &str) -> bool {
+    let Some(call) = before.trim_end().strip_suffix('(') else {
+        return false;
+    };
+    let call = call.trim_end();
+    let name_start = call
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map_or(0, |index| index + 1);
+
+    NAME_ARGUMENT_FUNCTIONS.contains(&&call[name_start..])
+}
+
+fn needs_quotes(literal: &str) -> bool {
+    literal.bytes().any(|b| b.is_ascii_digit())
+        && (literal.starts_with('-')
+            || literal.bytes().any(|b| b.is_ascii_alphabetic()))
+}
+
+/// Receiving end of the statements of [`send_statements`]
+pub type Statements = mpsc::Receiver<Result<Statement, String>>;
+
+/// Sending end of the statements, the drivers read them as they are rendered
+pub type StatementSender = mpsc::Sender<Result<Statement, String>>;
+
+/// Renders the entries of a seeder into `sender` one statement at a time,
+/// so rows read from a file are not kept in memory. The channel waits for
+/// the driver to read each statement, and a failure is sent last so the
+/// driver does not commit.
+pub async fn send_statements(
+    seeder: impl Into<Seeder>,
     database_url: &str,
-) -> Result<BTreeMap<String, RenderedTable>, String> {
-    let mut tables = BTreeMap::new();
+    mut sender: StatementSender,
+) {
+    let rendered = async {
+        let seeder = seeder.into().apply_templates()?;
+
+        let templating = start();
+        define_variables(&templating, &seeder.vars)?;
 
-    let templating = start();
+        render_entries(&templating, seeder.entries, database_url, &mut sender)
+            .await
+    }
+    .await;
+
+    if let Err(err) = rendered {
+        // The driver may have stopped reading after an error of its own
+        let _ = sender.send(Err(err)).await;
+    }
+}
+
+/// Sends a rendered statement, failing when the driver stopped reading
+async fn send(
+    sender: &mut StatementSender,
+    statement: Statement,
+) -> Result<(), String> {
+    sender
+        .send(Ok(statement))
+        .await
+        .map_err(|_| "The statements are no longer read".to_owned())
+}
+
+/// Adds the variables of a seeder, rendering their values in order so a
+/// variable can use `env(...)` or the variables declared before it.
+/// Variables given with `--var` are kept.
+fn define_variables(
+    templating: &SrTemplate,
+    vars: &[(String, String)],
+) -> Result<(), String> {
+    let overrides = super::variables::overrides();
 
+    for (name, value) in vars {
+        if overrides.iter().any(|(overridden, _)| overridden == name) {
+            continue;
+        }
+
+        let value = render(templating, value)
+            .map_err(|err| format!("Cannot resolve variable {name}: {err}"))?;
+        templating.add_variable(name.clone(), &value);
+    }
+
+    Ok(())
+}
+
+async fn render_entries(
+    templating: &SrTemplate<'_>,
+    entries: Vec<Entry>,
+    database_url: &str,
+    sender: &mut StatementSender,
+) -> Result<(), String> {
     for entry in entries {
+        // Every entry starts from the locale of the run
+        #[cfg(feature = "fake")]
+        super::fake::set_entry_locale(None);
+
         match entry {
             Entry::Repeat {
                 count,
                 table_name,
                 fields,
+                options,
             } => {
-                let table: &mut Vec<Vec<(String, SqlValue)>> =
-                    tables.entry(table_name.clone()).or_default();
+                check_unique_columns(&options, &table_name, |column| {
+                    fields.contains_key(column)
+                })?;
+
+                #[cfg(feature = "fake")]
+                super::fake::set_entry_locale(options.locale.as_deref());
 
                 for i in 0..count {
                     templating.add_variable("i", &i);
 
-                    let mut row = Vec::with_capacity(fields.len());
-
-                    for (key, value) in fields.iter() {
-                        let key = templating.render(key).map_err(|err| {
-                            format!(
-                                "Cannot resolve key of {table_name}.{key}: {err}"
-                            )
-                        })?;
-
-                        let rendered_value = match value {
-                            SqlValue::Text(text) => {
-                                let pre =
-                                    super::query::resolve_query_placeholders(
-                                        text,
-                                        database_url,
-                                    )
-                                    .await?;
-                                let rendered =
-                                    templating.render(&pre).map_err(|err| {
-                                        format!(
-                                            "Cannot resolve value of \
-                                             {table_name}.{key}: {err}"
-                                        )
-                                    })?;
-                                SqlValue::Text(rendered)
-                            }
-                            other => other.clone(),
-                        };
-
-                        row.push((key, rendered_value));
-                    }
-
-                    table.push(row)
+                    let row = render_row(
+                        templating,
+                        &fields,
+                        &table_name,
+                        &options,
+                        database_url,
+                    )
+                    .await?;
+
+                    send(
+                        sender,
+                        Statement::Insert {
+                            table: table_name.clone(),
+                            row,
+                        },
+                    )
+                    .await?;
                 }
 
                 templating.remove_variable("i");
             }
-            Entry::Static { table_name, values } => {
-                let table = tables.entry(table_name.clone()).or_default();
-
-                for fields in values {
-                    let mut row = Vec::with_capacity(fields.len());
-
-                    for (key, value) in fields.iter() {
-                        let key = templating.render(key).map_err(|err| {
-                            format!(
-                                "Cannot resolve key of {table_name}.{key}: {err}"
-                            )
-                        })?;
-
-                        let rendered_value = match value {
-                            SqlValue::Text(text) => {
-                                let pre =
-                                    super::query::resolve_query_placeholders(
-                                        text,
-                                        database_url,
-                                    )
-                                    .await?;
-                                let rendered =
-                                    templating.render(&pre).map_err(|err| {
-                                        format!(
-                                            "Cannot resolve value of \
-                                             {table_name}.{key}: {err}"
-                                        )
-                                    })?;
-                                SqlValue::Text(rendered)
-                            }
-                            other => other.clone(),
-                        };
-
-                        row.push((key, rendered_value));
-                    }
-
-                    table.push(row)
+            Entry::Static {
+                table_name,
+                values,
+                options,
+            } => {
+                render_rows(
+                    templating,
+                    sender,
+                    &table_name,
+                    values.into_iter().map(Ok),
+                    &options,
+                    true,
+                    database_url,
+                )
+                .await?;
+            }
+            Entry::Sql { statement } => {
+                let sql =
+                    render_sql(templating, &statement, database_url).await?;
+                send(sender, Statement::Sql(sql)).await?;
+            }
+            Entry::File {
+                table_name,
+                source,
+                render,
+                options,
+            } => {
+                let rows = source.read_rows()?;
+
+                render_rows(
+                    templating,
+                    sender,
+                    &table_name,
+                    rows,
+                    &options,
+                    render,
+                    database_url,
+                )
+                .await?;
+            }
+        }
+    }
+
+    #[cfg(feature = "fake")]
+    super::fake::set_entry_locale(None);
+
+    Ok(())
+}
+
+/// Renders rows one by one, without `render` the values are kept as they are
+async fn render_rows(
+    templating: &SrTemplate<'_>,
+    sender: &mut StatementSender,
+    table_name: &str,
+    rows: impl Iterator<Item = Result<BTreeMap<String, SqlValue>, String>>,
+    options: &EntryOptions,
+    render: bool,
+    database_url: &str,
+) -> Result<(), String> {
+    #[cfg(feature = "fake")]
+    super::fake::set_entry_locale(options.locale.as_deref());
+
+    // Rows may be streamed from a file, so columns are checked at the end
+    let mut columns = HashSet::new();
+
+    for fields in rows {
+        let fields = fields?;
+        columns.extend(fields.keys().cloned());
+
+        let row = if render {
+            render_row(templating, &fields, table_name, options, database_url)
+                .await?
+        } else {
+            literal_row(fields, table_name, options)?
+        };
+
+        send(
+            sender,
+            Statement::Insert {
+                table: table_name.to_owned(),
+                row,
+            },
+        )
+        .await?;
+    }
+
+    check_unique_columns(options, table_name, |column| columns.contains(column))
+}
+
+fn check_unique_columns(
+    options: &EntryOptions,
+    table_name: &str,
+    has_column: impl Fn(&str) -> bool,
+) -> Result<(), String> {
+    match options.unique.iter().find(|column| !has_column(column)) {
+        Some(column) => Err(format!(
+            "Unique column {table_name}.{column} is not a field of the entry"
+        )),
+        None => Ok(()),
+    }
+}
+
+async fn render_row(
+    templating: &SrTemplate<'_>,
+    fields: &BTreeMap<String, SqlValue>,
+    table_name: &str,
+    options: &EntryOptions,
+    database_url: &str,
+) -> Result<Vec<(String, SqlValue)>, String> {
+    let mut row = Vec::with_capacity(fields.len());
+
+    for (key, value) in fields.iter() {
+        let rendered_key = render(templating, key).map_err(|err| {
+            format!("Cannot resolve key of {table_name}.{key}: {err}")
+        })?;
+
+        let mut rendered_value = render_value(
+            templating,
+            value,
+            table_name,
+            &rendered_key,
+            database_url,
+        )
+        .await?;
+
+        if options.unique.contains(key) {
+            let mut attempts = 1;
+
+            while !unique::insert(table_name, key, &rendered_value)? {
+                if attempts == unique::MAX_ATTEMPTS {
+                    return Err(format!(
+                        "Cannot generate a unique value for {table_name}.{key} with `{value}` after {attempts} attempts"
+                    ));
                 }
+
+                rendered_value = render_value(
+                    templating,
+                    value,
+                    table_name,
+                    &rendered_key,
+                    database_url,
+                )
+                .await?;
+                attempts += 1;
             }
         }
+
+        row.push((rendered_key, rendered_value));
+    }
+
+    Ok(row)
+}
+
+/// Row of values that are not templates, a repeated unique value is an error
+/// since it cannot be generated again
+fn literal_row(
+    fields: BTreeMap<String, SqlValue>,
+    table_name: &str,
+    options: &EntryOptions,
+) -> Result<Vec<(String, SqlValue)>, String> {
+    for (key, value) in &fields {
+        if options.unique.contains(key)
+            && !unique::insert(table_name, key, value)?
+        {
+            return Err(format!(
+                "Repeated value `{value}` for the unique column {table_name}.{key}"
+            ));
+        }
     }
 
-    Ok(tables)
+    Ok(fields.into_iter().collect())
+}
+
+/// Renders the templates of a raw SQL statement
+async fn render_sql(
+    templating: &SrTemplate<'_>,
+    sql: &str,
+    database_url: &str,
+) -> Result<String, String> {
+    let pre =
+        super::query::resolve_query_placeholders(sql, database_url).await?;
+    super::sequence::resume_sequences(&pre, database_url).await?;
+
+    render(templating, &pre)
+        .map_err(|err| format!("Cannot resolve SQL `{sql}`: {err}"))
+}
+
+async fn render_value(
+    templating: &SrTemplate<'_>,
+    value: &SqlValue,
+    table_name: &str,
+    key: &str,
+    database_url: &str,
+) -> Result<SqlValue, String> {
+    match value {
+        SqlValue::Text(text) => {
+            let pre =
+                super::query::resolve_query_placeholders(text, database_url)
+                    .await?;
+            super::sequence::resume_sequences(&pre, database_url).await?;
+            let rendered = render(templating, &pre).map_err(|err| {
+                format!("Cannot resolve value of {table_name}.{key}: {err}")
+            })?;
+            Ok(typed_value(text, rendered))
+        }
+        other => Ok(other.clone()),
+    }
 }
@@ -0,0 +1,33 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+
+use grow_core::SqlValue;
+
+/// Renders of a `#[unique]` field before giving up on a repeated value
+pub const MAX_ATTEMPTS: usize = 100;
+
+/// Values given to every `#[unique]` column during the run, by
+/// `table.column`
+static VALUES: Mutex<BTreeMap<String, HashSet<String>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Records the value of a unique column, `false` when it was already used.
+/// `NULL` never repeats, as in SQL unique constraints.
+pub fn insert(
+    table_name: &str,
+    column: &str,
+    value: &SqlValue,
+) -> Result<bool, String> {
+    if value.is_null() {
+        return Ok(true);
+    }
+
+    let mut values = VALUES
+        .lock()
+        .map_err(|_| "Unique values are poisoned".to_owned())?;
+
+    Ok(values
+        .entry(format!("{table_name}.{column}"))
+        .or_default()
+        .insert(value.to_string()))
+}
@@ -0,0 +1,70 @@
+use std::sync::RwLock;
+
+use srtemplate::function::{self, FuncResult};
+use srtemplate::prelude::validations;
+
+/// Variables given with `grow run --var key=value`, they take precedence over
+/// the `#![vars(...)]` of the seeders
+static OVERRIDES: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Replaces the variables given on the command line
+pub fn set_overrides(variables: Vec<(String, String)>) {
+    if let Ok(mut overrides) = OVERRIDES.write() {
+        *overrides = variables;
+    }
+}
+
+/// Variables given on the command line
+pub fn overrides() -> Vec<(String, String)> {
+    OVERRIDES
+        .read()
+        .map(|overrides| overrides.clone())
+        .unwrap_or_default()
+}
+
+/// Parses a `key=value` assignment of `--var`
+pub fn parse_assignment(text: &str) -> Result<(String, String), String> {
+    let Some((name, value)) = text.split_once('=') else {
+        return Err(format!("Expected `key=value` in --var, found `{text}`"));
+    };
+
+    let name = name.trim();
+    validate_name(name)?;
+
+    Ok((name.to_owned(), value.to_owned()))
+}
+
+/// Checks that `name` can be used as `{name}` in templates
+pub fn validate_name(name: &str) -> Result<(), String> {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !valid {
+        return Err(format!("Invalid variable name `{name}`"));
+    }
+
+    if name == "i" {
+        return Err("Variable `i` is reserved for the iteration".to_owned());
+    }
+
+    Ok(())
+}
+
+/// `env(name[, default])`: value of an environment variable, variables of
+/// the `.env` file included
+pub fn env(args: &[String]) -> FuncResult {
+    validations::args_min_len(args, 1)?;
+    validations::args_max_len(args, 2)?;
+
+    match (std::env::var(&args[0]), args.get(1)) {
+        (Ok(value), _) => Ok(value),
+        (Err(_), Some(default)) => Ok(default.to_owned()),
+        (Err(_), None) => Err(function::Error::RuntimeError(format!(
+            "Environment variable {} is not set",
+            args[0]
+        ))),
+    }
+}
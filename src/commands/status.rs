@@ -1,35 +1,65 @@
-use crate::commands::run::seeder_tracker::SeederTracker;
+use crate::commands::run::connection::{self, Trackers};
+use crate::commands::run::environment;
 use crate::utils;
-use std::env;
+use crate::utils::config::DEFAULT_CONNECTION;
 use std::error::Error;
 
+/// Status of the seeders that run in the active environment, grouped by
+/// connection when some seeder has a `#![connection = "..."]`
 pub async fn list_seeders_status() -> Result<(), Box<dyn Error>> {
-    let seeders = utils::list_seeders().await?;
+    let config = utils::config::current();
+    let active_env = config.environment.value.clone();
+    let root = utils::get_seeders().await?;
+    let seeders = environment::filter(
+        &root,
+        utils::seeder_names(&root)?,
+        active_env.as_deref(),
+    );
 
     if seeders.is_empty() {
         println!("No seeders available in the seeders directory.");
         return Ok(());
     }
 
-    let database_url = env::var("DATABASE_URL").map_err(|_| {
-        "Please, be sure to set the `DATABASE_URL` environment variable."
-    })?;
+    let groups = connection::group(&root, seeders);
+    let named = groups
+        .iter()
+        .any(|(connection, _)| connection != DEFAULT_CONNECTION);
+    let mut trackers = Trackers::new(config);
 
-    let tracker = SeederTracker::new(database_url)?;
-    tracker.ensure_seeds_table().await?;
+    if let Some(active_env) = &active_env {
+        println!("\nEnvironment: {active_env}");
+    }
+
+    for (connection, seeders) in groups {
+        let tracker = trackers.get(&connection).await?;
+
+        if named {
+            println!(
+                "\nConnection: {connection} ({})",
+                utils::config::mask_url(tracker.database_url())
+            );
+        }
+
+        println!("\n{:<30} {:<10}", "Seeder Name", "Status");
+        println!("{}", "-".repeat(42));
 
-    println!("\n{:<30} {:<10}", "Seeder Name", "Status");
-    println!("{}", "-".repeat(42));
+        for (depth, line, seeder) in utils::seeder_tree(&seeders) {
+            let line = format!("{}{line}", "  ".repeat(depth));
+            let Some(seeder) = seeder else {
+                println!("{line}");
+                continue;
+            };
 
-    for seeder in seeders {
-        let is_executed = tracker.is_seeder_executed(&seeder).await?;
-        let status = if is_executed {
-            "✅ Executed"
-        } else {
-            "⏳ Pending"
-        };
+            let is_executed = tracker.is_seeder_executed(seeder).await?;
+            let status = if is_executed {
+                "✅ Executed"
+            } else {
+                "⏳ Pending"
+            };
 
-        println!("{:<30} {:<10}", seeder, status);
+            println!("{:<30} {:<10}", line, status);
+        }
     }
 
     println!();
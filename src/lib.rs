@@ -1,5 +1,5 @@
-// Re-export SqlValue from grow_core for convenience
-pub use grow_core::SqlValue;
+// Re-export the values and statements from grow_core for convenience
+pub use grow_core::{SqlValue, Statement};
 
 // Public modules for testing
 pub mod commands;
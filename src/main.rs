@@ -15,8 +15,92 @@ enum Commands {
         file_name: Option<String>,
         #[clap(long, help = "Execute all pending seeders")]
         all: bool,
+        #[clap(
+            long,
+            help = "Execute the pending seeders of a subfolder, e.g. `demo`"
+        )]
+        group: Option<String>,
+        #[clap(
+            long,
+            help = "Freeze the clock of `now()` and other time functions, e.g. \"2024-01-01 00:00:00\""
+        )]
+        now: Option<String>,
+        #[clap(
+            long = "var",
+            value_name = "KEY=VALUE",
+            help = "Define a template variable, overrides `#![vars(...)]` (repeatable)"
+        )]
+        vars: Vec<String>,
+        #[clap(
+            long,
+            help = "Locale of plain `fake` kinds such as FIRST_NAME, e.g. PT_BR. GROW_LOCALE takes precedence"
+        )]
+        locale: Option<String>,
+        #[clap(
+            long,
+            help = "Active environment, seeders with another `#![env(...)]` are skipped. GROW_ENV takes precedence"
+        )]
+        env: Option<String>,
+        #[clap(long, help = "Run a seeder outside of its environments")]
+        force: bool,
+    },
+    Status {
+        #[clap(
+            long,
+            help = "Only show the seeders of this environment. GROW_ENV takes precedence"
+        )]
+        env: Option<String>,
+    },
+    Config {
+        #[clap(subcommand)]
+        command: ConfigCommands,
+    },
+    #[cfg(feature = "fake")]
+    Fake {
+        #[clap(subcommand)]
+        command: FakeCommands,
+    },
+}
+
+#[cfg(feature = "fake")]
+#[derive(Subcommand)]
+enum FakeCommands {
+    #[clap(about = "List the fake kinds with a sample value")]
+    List {
+        #[clap(long, help = "Show the kinds of a locale, e.g. PT_BR")]
+        locale: Option<String>,
+        #[clap(long, help = "Only show kinds whose name or module match")]
+        search: Option<String>,
+    },
+    #[clap(about = "Print sample values of a fake kind")]
+    Sample {
+        kind: String,
+        #[clap(
+            short = 'n',
+            long,
+            default_value_t = 5,
+            help = "Number of values"
+        )]
+        count: usize,
+        #[clap(
+            help = "Arguments of the kind, e.g. `grow fake sample WORDS 3 6`"
+        )]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    #[clap(
+        about = "Print the resolved settings of grow.toml and their source"
+    )]
+    Show {
+        #[clap(
+            long,
+            help = "Resolve the settings of this environment. GROW_ENV takes precedence"
+        )]
+        env: Option<String>,
     },
-    Status,
 }
 
 #[derive(Parser)]
@@ -27,27 +111,86 @@ struct Cli {
     command: Commands,
 }
 
-#[tokio::main]
-async fn main() {
+fn main() {
     dotenv().ok();
     let cli = Cli::parse();
 
+    let flags = match &cli.command {
+        Commands::Run { env, locale, .. } => utils::config::Flags {
+            env: env.clone(),
+            locale: locale.clone(),
+        },
+        Commands::Status { env }
+        | Commands::Config {
+            command: ConfigCommands::Show { env },
+        } => utils::config::Flags {
+            env: env.clone(),
+            locale: None,
+        },
+        _ => utils::config::Flags::default(),
+    };
+
+    // Loading exports the credentials of grow.toml to the environment, which
+    // is only safe before the runtime starts its threads
+    if let Err(e) = utils::config::load(&flags) {
+        eprintln!("\x1b[1;31;91m[ERROR] {e}\x1b[0m");
+        return;
+    }
+
+    match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime.block_on(run_command(cli)),
+        Err(e) => eprintln!("\x1b[1;31;91m[ERROR] {e}\x1b[0m"),
+    }
+}
+
+async fn run_command(cli: Cli) {
     commands::ensure_seeders_dir();
 
     match &cli.command {
         Commands::Init => commands::init_seeder(),
         Commands::New { name } => commands::create_seeder(name),
         Commands::List => commands::list_seeders(),
-        Commands::Run { file_name, all } => {
-            if let Err(e) = commands::run_seeder(file_name.as_ref(), *all).await
+        Commands::Run {
+            file_name,
+            all,
+            group,
+            now,
+            vars,
+            force,
+            ..
+        } => {
+            let options = commands::RunOptions {
+                all: *all,
+                group: group.as_deref(),
+                now: now.as_deref(),
+                vars,
+                force: *force,
+            };
+
+            if let Err(e) =
+                commands::run_seeder(file_name.as_ref(), options).await
             {
                 eprintln!("\x1b[1;31;91m[ERROR] {e}\x1b[0m");
             }
         }
-        Commands::Status => {
+        Commands::Status { .. } => {
             if let Err(e) = commands::list_seeders_status().await {
                 eprintln!("\x1b[1;31;91m[ERROR] {e}\x1b[0m");
             }
         }
+        Commands::Config {
+            command: ConfigCommands::Show { .. },
+        } => commands::show_config(),
+        #[cfg(feature = "fake")]
+        Commands::Fake { command } => match command {
+            FakeCommands::List { locale, search } => {
+                commands::list_fakers(locale.as_deref(), search.as_deref())
+            }
+            FakeCommands::Sample { kind, count, args } => {
+                if let Err(e) = commands::sample_faker(kind, *count, args) {
+                    eprintln!("\x1b[1;31;91m[ERROR] {e}\x1b[0m");
+                }
+            }
+        },
     }
 }
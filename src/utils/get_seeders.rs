@@ -1,13 +1,13 @@
 use core::fmt;
+use std::io;
 use std::path::{Path, PathBuf};
-use std::{env, io};
 
 use tokio::fs;
 
+use crate::commands::run::seeder_format::{self, SeederFormat};
+
 pub async fn get_seeders() -> Result<PathBuf, String> {
-    let seeders_path = env::var("GROW_SEEDERS")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| Path::new("seeders").to_path_buf());
+    let seeders_path = super::config::current().seeders.value;
 
     match seeders_path.metadata() {
         Ok(m) if !m.is_dir() => {
@@ -36,33 +36,116 @@ pub fn map_io_error(path: impl fmt::Debug) -> impl Fn(io::Error) -> String {
     }
 }
 
-pub async fn list_seeders() -> Result<Vec<String>, String> {
-    let seeders_path = get_seeders().await?;
-
-    let mut entries = fs::read_dir(&seeders_path)
-        .await
-        .map_err(map_io_error(&seeders_path))?;
-
-    let mut seeders = Vec::new();
-
-    while let Some(entry) = entries
-        .next_entry()
-        .await
-        .map_err(map_io_error(&seeders_path))?
-    {
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(extension) = path.extension() {
-                if extension == "ron" {
-                    if let Some(file_stem) = path.file_stem() {
-                        if let Some(name) = file_stem.to_str() {
-                            seeders.push(name.to_string());
-                        }
-                    }
-                }
-            }
+/// Names of the seeders of `root` and its subfolders, as their path relative
+/// to it without the extension, e.g. `demo/users`, in the order they run.
+/// Partials and data files are skipped.
+pub fn seeder_names(root: &Path) -> Result<Vec<String>, String> {
+    let mut names = Vec::new();
+    collect_seeder_names(root, root, &mut names)?;
+    sort_seeders(&mut names);
+    // A name is run once, even when it is written in several formats
+    names.dedup();
+
+    Ok(names)
+}
+
+/// Seeders without a `TIMESTAMP_` prefix, which run after the others
+pub fn unprefixed_seeders(names: &[String]) -> Vec<&String> {
+    names
+        .iter()
+        .filter(|name| seeder_timestamp(name).is_none())
+        .collect()
+}
+
+/// Warns about each seeder without a `TIMESTAMP_` prefix
+pub fn warn_unprefixed(names: &[String]) {
+    for name in unprefixed_seeders(names) {
+        eprintln!(
+            "\x1b[1;33m[WARN] {name} has no TIMESTAMP_ prefix, it runs after the seeders with one\x1b[0m"
+        );
+    }
+}
+
+/// Timestamp of the `TIMESTAMP_NAME` prefix of a seeder. Seeders in
+/// subfolders are named by their path, e.g. `demo/1700000000_users`.
+pub fn seeder_timestamp(name: &str) -> Option<i64> {
+    let file_name = name.rsplit('/').next().unwrap_or(name);
+
+    file_name
+        .split_once('_')
+        .and_then(|(timestamp, _)| timestamp.parse().ok())
+}
+
+/// Sorts the seeders in the order they run: by the timestamp of their
+/// prefix, then by name, and the seeders without a prefix last
+fn sort_seeders(names: &mut [String]) {
+    names.sort_by_cached_key(|name| {
+        let timestamp = seeder_timestamp(name);
+        (timestamp.is_none(), timestamp, name.clone())
+    });
+}
+
+fn collect_seeder_names(
+    root: &Path,
+    folder: &Path,
+    names: &mut Vec<String>,
+) -> Result<(), String> {
+    for entry in std::fs::read_dir(folder).map_err(map_io_error(folder))? {
+        let path = entry.map_err(map_io_error(folder))?.path();
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+
+        if seeder_format::is_partial(relative) {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_seeder_names(root, &path, names)?;
+        } else if SeederFormat::from_path(&path)
+            .is_some_and(|format| format.is_seeder(&path))
+        {
+            names.push(
+                relative
+                    .with_extension("")
+                    .components()
+                    .map(|component| {
+                        component.as_os_str().to_string_lossy().into_owned()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Lines of the seeder names as a tree, with their depth: each folder ending
+/// with `/` and followed by its seeders one level deeper. The order is kept,
+/// so a folder is repeated when its seeders run between others.
+pub fn seeder_tree(names: &[String]) -> Vec<(usize, String, Option<&String>)> {
+    let mut lines = Vec::new();
+    let mut folders: Vec<&str> = Vec::new();
+
+    for name in names {
+        let mut parts: Vec<&str> = name.split('/').collect();
+        let file = parts.pop().unwrap_or_default();
+
+        let common = folders
+            .iter()
+            .zip(&parts)
+            .take_while(|(open, part)| open == part)
+            .count();
+        folders.truncate(common);
+
+        for part in &parts[common..] {
+            lines.push((folders.len(), format!("{part}/"), None));
+            folders.push(part);
         }
+
+        lines.push((folders.len(), file.to_owned(), Some(name)));
     }
 
-    Ok(seeders)
+    lines
 }
@@ -1,2 +1,6 @@
+pub mod config;
 pub mod get_seeders;
-pub use get_seeders::{get_seeders, list_seeders, map_io_error};
+pub use get_seeders::{
+    get_seeders, map_io_error, seeder_names, seeder_timestamp, seeder_tree,
+    warn_unprefixed,
+};
@@ -0,0 +1,112 @@
+use chrono::NaiveDate;
+use grow_rs::commands::run::clock::{
+    format_date, now, now_offset, timestamp_add, today,
+};
+use grow_rs::commands::run::datetime::{
+    freeze_clock, parse_duration, parse_timestamp,
+};
+use grow_rs::commands::run::template::{render, start};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+// The clock is global, so every frozen assertion lives in one test
+#[test]
+fn test_frozen_clock() {
+    let frozen = NaiveDate::from_ymd_opt(2024, 3, 1)
+        .unwrap()
+        .and_hms_opt(10, 30, 0)
+        .unwrap();
+    freeze_clock(Some(frozen));
+
+    assert_eq!(now(&[]).unwrap(), "2024-03-01 10:30:00");
+    assert_eq!(today(&[]).unwrap(), "2024-03-01");
+    assert_eq!(now_offset(&args(&["-7d"])).unwrap(), "2024-02-23 10:30:00");
+    assert_eq!(now_offset(&args(&["90m"])).unwrap(), "2024-03-01 12:00:00");
+
+    let templating = start();
+    templating.add_variable("i", &5);
+    assert_eq!(
+        render(&templating, "{now_offset(-1d)}").unwrap(),
+        "2024-02-29 10:30:00"
+    );
+    assert_eq!(
+        render(&templating, "{timestamp_add(now(), i, \"minutes\")}").unwrap(),
+        "2024-03-01 10:35:00"
+    );
+    assert_eq!(
+        render(&templating, "{format_date(today(), \"%d/%m/%Y\")}").unwrap(),
+        "01/03/2024"
+    );
+
+    freeze_clock(None);
+    let current = parse_timestamp(&now(&[]).unwrap()).unwrap();
+    assert!(current > frozen);
+}
+
+#[test]
+fn test_timestamp_add() {
+    assert_eq!(
+        timestamp_add(&args(&["2024-01-31 23:00:00", "2", "hours"])).unwrap(),
+        "2024-02-01 01:00:00"
+    );
+    assert_eq!(
+        timestamp_add(&args(&["2024-01-31", "-1", "weeks"])).unwrap(),
+        "2024-01-24 00:00:00"
+    );
+    assert!(timestamp_add(&args(&["2024-01-31", "x", "days"])).is_err());
+    assert!(timestamp_add(&args(&["2024-01-31", "1", "years"])).is_err());
+    assert!(timestamp_add(&args(&["not a date", "1", "days"])).is_err());
+}
+
+#[test]
+fn test_format_date() {
+    assert_eq!(
+        format_date(&args(&["2024-01-31 08:05:00", "%H:%M"])).unwrap(),
+        "08:05"
+    );
+    assert_eq!(
+        format_date(&args(&["2024-01-31", "%Y%m%d"])).unwrap(),
+        "20240131"
+    );
+    assert!(format_date(&args(&["2024-01-31", "%Q"])).is_err());
+    assert!(format_date(&args(&["2024-01-31"])).is_err());
+}
+
+#[test]
+fn test_clock_arguments() {
+    assert!(now(&args(&["1"])).is_err());
+    assert!(today(&args(&["1"])).is_err());
+    assert!(now_offset(&[]).is_err());
+    assert!(now_offset(&args(&["soon"])).is_err());
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("30d"), Ok(chrono::Duration::days(30)));
+    assert_eq!(parse_duration("-7d"), Ok(chrono::Duration::days(-7)));
+    assert_eq!(parse_duration("12h"), Ok(chrono::Duration::hours(12)));
+    assert_eq!(parse_duration("15m"), Ok(chrono::Duration::minutes(15)));
+    assert_eq!(parse_duration("90s"), Ok(chrono::Duration::seconds(90)));
+    assert_eq!(parse_duration("2w"), Ok(chrono::Duration::weeks(2)));
+    assert_eq!(parse_duration("3"), Ok(chrono::Duration::days(3)));
+    assert!(parse_duration("3y").is_err());
+    assert!(parse_duration("d").is_err());
+}
+
+#[test]
+fn test_parse_timestamp_formats() {
+    let expected = chrono::NaiveDate::from_ymd_opt(2024, 1, 31)
+        .unwrap()
+        .and_hms_opt(12, 30, 0)
+        .unwrap();
+
+    assert_eq!(parse_timestamp("2024-01-31 12:30:00"), Ok(expected));
+    assert_eq!(parse_timestamp("2024-01-31T12:30:00"), Ok(expected));
+    assert_eq!(parse_timestamp("2024-01-31T12:30:00Z"), Ok(expected));
+    assert_eq!(
+        parse_timestamp("2024-01-31"),
+        Ok(expected.date().and_hms_opt(0, 0, 0).unwrap())
+    );
+}
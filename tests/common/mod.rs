@@ -0,0 +1,68 @@
+//! Helpers shared by the integration tests, each test file uses some of them
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::channel::mpsc;
+use futures::StreamExt;
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::template;
+use grow_rs::{SqlValue, Statement};
+
+pub type Tables = BTreeMap<String, Vec<Vec<(String, SqlValue)>>>;
+
+/// Statements of a seeder in source order, read as the driver would
+pub async fn render_statements(
+    seeder: impl Into<Seeder>,
+    database_url: &str,
+) -> Result<Vec<Statement>, String> {
+    let (sender, statements) = mpsc::channel(0);
+
+    let ((), statements) = futures::join!(
+        template::send_statements(seeder, database_url, sender),
+        statements.collect::<Vec<_>>()
+    );
+
+    statements.into_iter().collect()
+}
+
+/// Rows inserted by a seeder, grouped by table in the order they are
+/// rendered. Raw SQL is left out.
+pub async fn render_tables(
+    seeder: impl Into<Seeder>,
+    database_url: &str,
+) -> Result<Tables, String> {
+    let mut tables = Tables::new();
+
+    for statement in render_statements(seeder, database_url).await? {
+        if let Statement::Insert { table, row } = statement {
+            tables.entry(table).or_default().push(row);
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Seeders folder with the given files, relative to it. Each call gets its
+/// own folder, so the tests can run in parallel.
+pub fn seeders_folder(files: &[(&str, &str)]) -> PathBuf {
+    static FOLDERS: AtomicUsize = AtomicUsize::new(0);
+
+    let root = std::env::temp_dir().join(format!(
+        "grow_seeders_{}_{}",
+        std::process::id(),
+        FOLDERS.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = fs::remove_dir_all(&root);
+
+    for (file, content) in files {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    root
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::seeders_folder;
+use std::path::Path;
+
+use grow_rs::commands::run::connection::{group, seeder_connection};
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::seeder_format::SeederFormat;
+use grow_rs::utils::config::{Config, ConfigFile, Flags};
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_parse_connection() {
+    let ron = Seeder::parse(
+        "#![connection = \"edge\"]\n{ users: [(name: \"ana\")] }".to_owned(),
+    )
+    .unwrap();
+    assert_eq!(ron.connection.as_deref(), Some("edge"));
+    assert_eq!(ron.entries.len(), 1);
+
+    let yaml = SeederFormat::Yaml
+        .parse("grow:\n  connection: edge\nusers: []\n".to_owned())
+        .unwrap();
+    assert_eq!(yaml.connection.as_deref(), Some("edge"));
+
+    assert!(Seeder::parse("{}".to_owned()).unwrap().connection.is_none());
+    assert!(Seeder::parse("#![connection(\"a\", \"b\")] {}".to_owned())
+        .unwrap_err()
+        .starts_with("Expect one connection name"));
+}
+
+#[test]
+fn test_group() {
+    let root = seeders_folder(&[
+        ("1_roles.ron", "{}"),
+        ("2_events.ron", "#![connection = \"edge\"]\n{}"),
+        ("3_users.ron", "{}"),
+        ("4_sessions.yaml", "grow:\n  connection: edge\n"),
+        ("5_broken.ron", "{"),
+    ]);
+
+    assert_eq!(seeder_connection(&root, "2_events").unwrap(), "edge");
+    assert_eq!(seeder_connection(&root, "3_users").unwrap(), "default");
+    assert_eq!(
+        group(
+            &root,
+            names(&[
+                "1_roles",
+                "2_events",
+                "3_users",
+                "4_sessions",
+                "5_broken"
+            ])
+        ),
+        vec![
+            (
+                "default".to_owned(),
+                names(&["1_roles", "3_users", "5_broken"])
+            ),
+            ("edge".to_owned(), names(&["2_events", "4_sessions"])),
+        ]
+    );
+}
+
+#[test]
+fn test_connection_url() {
+    let file = ConfigFile::parse(
+        Path::new("grow.toml"),
+        r#"
+database_url = "postgres://localhost/app"
+
+[connections.edge]
+url = "libsql://edge.turso.io"
+"#,
+    )
+    .unwrap();
+    let config =
+        Config::resolve(Some(file), &Flags::default(), |_| None).unwrap();
+
+    assert_eq!(
+        config.connection_url("default").unwrap(),
+        "postgres://localhost/app"
+    );
+    assert_eq!(
+        config.connection_url("edge").unwrap(),
+        "libsql://edge.turso.io"
+    );
+    assert_eq!(
+        config.connection_url("replica").unwrap_err(),
+        "Unknown connection `replica`, define it in [connections] of grow.toml"
+    );
+
+    let parse = |content: &str| {
+        ConfigFile::parse(Path::new("grow.toml"), content).unwrap_err()
+    };
+    assert_eq!(
+        parse("[connections.edge]\nhost = \"x\""),
+        "Unknown key `host` of [connections.edge]"
+    );
+    assert_eq!(
+        parse("[connections.edge]"),
+        "Missing `url` of [connections.edge]"
+    );
+    assert!(parse("[connections]\ndefault = \"sqlite://a.db\"")
+        .starts_with("`default` is the connection of `database_url`"));
+}
@@ -0,0 +1,256 @@
+mod common;
+
+use common::render_tables;
+use std::path::PathBuf;
+
+use grow_rs::commands::run::data_file::{cell_value, json_value};
+use grow_rs::commands::run::entry::{Entry, Seeder};
+use grow_rs::commands::run::template;
+use grow_rs::SqlValue;
+
+fn write_csv(name: &str, content: &str) -> PathBuf {
+    write_file(&format!("{name}.csv"), content)
+}
+
+fn write_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("grow_{name}"));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn csv_seeder(attributes: &str, path: &PathBuf) -> Result<Seeder, String> {
+    Seeder::parse(format!(
+        "{{ #[csv = {path:?}] {attributes} countries: () }}"
+    ))
+}
+
+#[test]
+fn test_cell_value() {
+    assert_eq!(cell_value(""), SqlValue::Null);
+    assert_eq!(cell_value("42"), SqlValue::Integer(42));
+    assert_eq!(cell_value("-7"), SqlValue::Integer(-7));
+    assert_eq!(cell_value("0"), SqlValue::Integer(0));
+    assert_eq!(cell_value("0.5"), SqlValue::Float(0.5));
+    assert_eq!(cell_value("true"), SqlValue::Boolean(true));
+    assert_eq!(cell_value("007"), SqlValue::Text("007".to_string()));
+    assert_eq!(cell_value("12ab"), SqlValue::Text("12ab".to_string()));
+    assert_eq!(cell_value("Mexico"), SqlValue::Text("Mexico".to_string()));
+}
+
+#[tokio::test]
+async fn test_render_csv_entry() {
+    let path = write_csv(
+        "countries",
+        "code, name, population\nMX,Mexico,126\nJP,\"Japan, {i}\",\n",
+    );
+
+    let seeder = csv_seeder("", &path).unwrap();
+    assert!(matches!(
+        seeder.entries[0],
+        Entry::File { render: false, .. }
+    ));
+
+    let tables = render_tables(seeder, "mock://").await.unwrap();
+    let countries = &tables["countries"];
+
+    assert_eq!(countries.len(), 2);
+    assert_eq!(
+        countries[0],
+        vec![
+            ("code".to_string(), SqlValue::Text("MX".to_string())),
+            ("name".to_string(), SqlValue::Text("Mexico".to_string())),
+            ("population".to_string(), SqlValue::Integer(126)),
+        ]
+    );
+    // Cells are not templates by default
+    assert_eq!(countries[1][1].1, SqlValue::Text("Japan, {i}".to_string()));
+    assert_eq!(countries[1][2].1, SqlValue::Null);
+}
+
+#[tokio::test]
+async fn test_render_csv_entry_templates() {
+    let path = write_csv(
+        "templates",
+        "name,slug\nAlice,\"{env(GROW_CSV_PREFIX, \"\"user\"\")}-1\"\n",
+    );
+
+    let seeder = csv_seeder("#[render = true]", &path).unwrap();
+    let tables = render_tables(seeder, "mock://").await.unwrap();
+
+    assert_eq!(
+        tables["countries"][0][1].1,
+        SqlValue::Text("user-1".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_csv_entry_errors() {
+    let path = write_csv("repeated", "code\nMX\nMX\n");
+
+    let seeder = csv_seeder(r#"#[unique = "code"]"#, &path).unwrap();
+    let err = render_tables(seeder, "mock://").await.unwrap_err();
+    assert!(err.contains("Repeated value `MX`"), "{err}");
+
+    let err = csv_seeder("#[repeat = 2]", &path).unwrap_err();
+    assert!(err.contains("cannot be used with #[repeat]"), "{err}");
+
+    let missing = std::env::temp_dir().join("grow_missing.csv");
+    let seeder = csv_seeder("", &missing).unwrap();
+    assert!(render_tables(seeder, "mock://").await.is_err());
+}
+
+fn json_seeder(attributes: &str, path: &PathBuf) -> Result<Seeder, String> {
+    Seeder::parse(format!("{{ #[json = {path:?}] {attributes} users: () }}"))
+}
+
+#[test]
+fn test_json_value() {
+    assert_eq!(json_value(serde_json::json!(null)), SqlValue::Null);
+    assert_eq!(json_value(serde_json::json!(42)), SqlValue::Integer(42));
+    assert_eq!(json_value(serde_json::json!(0.5)), SqlValue::Float(0.5));
+    assert_eq!(json_value(serde_json::json!(true)), SqlValue::Boolean(true));
+    assert_eq!(
+        json_value(serde_json::json!("007")),
+        SqlValue::Text("007".to_string())
+    );
+    assert_eq!(
+        json_value(serde_json::json!({ "theme": "dark" })),
+        SqlValue::Json(r#"{"theme":"dark"}"#.to_string())
+    );
+    assert_eq!(
+        json_value(serde_json::json!([1, 2])),
+        SqlValue::Json("[1,2]".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_render_ndjson_entry() {
+    let path = write_file(
+        "users.ndjson",
+        concat!(
+            r#"{"name": "Alice", "age": 30, "settings": {"theme": "dark"}}"#,
+            "\n\n",
+            r#"{"name": "Bob {i}", "age": null, "tags": ["admin"]}"#,
+            "\n",
+        ),
+    );
+
+    let seeder = json_seeder("", &path).unwrap();
+    let tables = render_tables(seeder, "mock://").await.unwrap();
+    let users = &tables["users"];
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(
+        users[0],
+        vec![
+            ("age".to_string(), SqlValue::Integer(30)),
+            ("name".to_string(), SqlValue::Text("Alice".to_string())),
+            (
+                "settings".to_string(),
+                SqlValue::Json(r#"{"theme":"dark"}"#.to_string())
+            ),
+        ]
+    );
+    assert_eq!(users[1][0].1, SqlValue::Null);
+    assert_eq!(users[1][1].1, SqlValue::Text("Bob {i}".to_string()));
+    assert_eq!(users[1][2].1, SqlValue::Json(r#"["admin"]"#.to_string()));
+}
+
+#[tokio::test]
+async fn test_render_json_array_entry() {
+    let path = write_file(
+        "users.json",
+        r#"[{"email": "{env(GROW_JSON_DOMAIN, \"a@grow.dev\")}"}, {"email": "b@grow.dev"}]"#,
+    );
+
+    let seeder = json_seeder("#[render = true]", &path).unwrap();
+    let tables = render_tables(seeder, "mock://").await.unwrap();
+
+    assert_eq!(
+        tables["users"][0][0].1,
+        SqlValue::Text("a@grow.dev".to_string())
+    );
+    assert_eq!(
+        tables["users"][1][0].1,
+        SqlValue::Text("b@grow.dev".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_json_entry_errors() {
+    let path = write_file("invalid.ndjson", "{\"name\": \"Alice\"}\n[1, 2]\n");
+    let seeder = json_seeder("", &path).unwrap();
+    let err = render_tables(seeder, "mock://").await.unwrap_err();
+    assert!(err.contains(":2: Expected an object"), "{err}");
+
+    let err = json_seeder("#[repeat = 2]", &path).unwrap_err();
+    assert!(
+        err.contains("#[json] cannot be used with #[repeat]"),
+        "{err}"
+    );
+
+    let err = json_seeder(r#"#[csv = "users.csv"]"#, &path).unwrap_err();
+    assert!(err.contains("cannot be used with #[json]"), "{err}");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_ndjson_rows_sent_while_reading() {
+    use futures::StreamExt;
+    use grow_rs::Statement;
+    use std::io::Write;
+    use std::time::Duration;
+
+    // A FIFO only ends when its writer closes it, which waits for a row to
+    // be received
+    let path = std::env::temp_dir().join("grow_streamed.ndjson");
+    let _ = std::fs::remove_file(&path);
+    let created = std::process::Command::new("mkfifo")
+        .arg(&path)
+        .status()
+        .unwrap();
+    assert!(created.success());
+
+    let (received, wait_received) = std::sync::mpsc::channel();
+    let fifo = path.clone();
+    let writer = std::thread::spawn(move || {
+        let mut file =
+            std::fs::OpenOptions::new().write(true).open(fifo).unwrap();
+        writeln!(file, "{{\"name\": \"Alice\"}}\n{{\"name\": \"Bob\"}}")
+            .unwrap();
+        file.flush().unwrap();
+
+        let waited = wait_received.recv_timeout(Duration::from_secs(10));
+        writeln!(file, "{{\"name\": \"Carol\"}}").unwrap();
+        waited.is_ok()
+    });
+
+    let seeder = json_seeder("", &path).unwrap();
+    let (sender, mut statements) = futures::channel::mpsc::channel(0);
+
+    let read = async {
+        let first = statements.next().await;
+        let _ = received.send(());
+        (first, statements.collect::<Vec<_>>().await)
+    };
+    let ((), (first, rest)) = futures::join!(
+        template::send_statements(seeder, "mock://", sender),
+        read
+    );
+
+    assert!(
+        writer.join().unwrap(),
+        "no row was sent before the end of the file"
+    );
+    assert_eq!(
+        first,
+        Some(Ok(Statement::Insert {
+            table: "users".to_string(),
+            row: vec![("name".to_string(), SqlValue::Text("Alice".into()))],
+        }))
+    );
+    assert_eq!(rest.len(), 2);
+    assert!(rest.iter().all(Result::is_ok));
+
+    std::fs::remove_file(&path).unwrap();
+}
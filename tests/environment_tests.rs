@@ -0,0 +1,88 @@
+mod common;
+
+use common::seeders_folder;
+
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::environment::{self, matches};
+use grow_rs::commands::run::seeder_format::SeederFormat;
+
+fn environments(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_matches() {
+    let dev_test = environments(&["dev", "test"]);
+
+    assert!(matches(&[], None));
+    assert!(matches(&[], Some("staging")));
+    assert!(matches(&dev_test, Some("dev")));
+    assert!(matches(&dev_test, Some("test")));
+    assert!(!matches(&dev_test, Some("staging")));
+    assert!(!matches(&dev_test, None));
+}
+
+#[test]
+fn test_parse_environments() {
+    let ron = Seeder::parse(
+        r#"
+#![env("dev", "test")]
+
+{ users: [(name: "demo")] }
+"#
+        .to_owned(),
+    )
+    .unwrap();
+    assert_eq!(ron.environments, environments(&["dev", "test"]));
+    assert_eq!(ron.entries.len(), 1);
+
+    let yaml = SeederFormat::Yaml
+        .parse("grow:\n  env: dev\nusers:\n  - name: demo\n".to_owned())
+        .unwrap();
+    assert_eq!(yaml.environments, environments(&["dev"]));
+
+    let json = SeederFormat::Json
+        .parse(
+            r#"{ "grow": { "env": ["dev", "test"] }, "users": [] }"#.to_owned(),
+        )
+        .unwrap();
+    assert_eq!(json.environments, environments(&["dev", "test"]));
+
+    assert!(Seeder::parse("#![env(1)] {}".to_owned())
+        .unwrap_err()
+        .starts_with("Expect environment names in #![env(...)]"));
+}
+
+#[test]
+fn test_filter_and_check() {
+    let root = seeders_folder(&[
+        ("1_countries.ron", "{ countries: [(name: \"AR\")] }"),
+        ("2_demo.ron", "#![env(\"dev\", \"test\")]\n{}"),
+        ("3_staging.yaml", "grow:\n  env: staging\n"),
+    ]);
+    let names = environments(&["1_countries", "2_demo", "3_staging"]);
+
+    assert_eq!(
+        environment::filter(&root, names.clone(), Some("dev")),
+        environments(&["1_countries", "2_demo"])
+    );
+    assert_eq!(
+        environment::filter(&root, names.clone(), Some("staging")),
+        environments(&["1_countries", "3_staging"])
+    );
+    assert_eq!(
+        environment::filter(&root, names, None),
+        environments(&["1_countries"])
+    );
+
+    assert!(environment::check(&root, "1_countries", None).is_ok());
+    assert!(environment::check(&root, "2_demo", Some("test")).is_ok());
+    assert_eq!(
+        environment::check(&root, "2_demo", Some("staging")).unwrap_err(),
+        "2_demo only runs in dev, test, not in staging. Use --force to run it anyway"
+    );
+    assert_eq!(
+        environment::check(&root, "2_demo", None).unwrap_err(),
+        "2_demo only runs in dev, test, but no environment is set with --env or GROW_ENV. Use --force to run it anyway"
+    );
+}
@@ -0,0 +1,141 @@
+use grow_rs::commands::run::expression::{
+    choice, evaluate, if_then_else, needs_evaluation, weighted,
+};
+use grow_rs::commands::run::template::{render, start};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_needs_evaluation() {
+    assert!(needs_evaluation("i % 10 == 0"));
+    assert!(needs_evaluation(r#"weighted("free":80)"#));
+    assert!(!needs_evaluation(r#"fake(FREE_EMAIL)"#));
+    assert!(!needs_evaluation(r#"now_offset("-7d")"#));
+    assert!(!needs_evaluation(r#"format_date(now(), "%Y-%m-%d")"#));
+    assert!(needs_evaluation("-i + 1"));
+    assert!(needs_evaluation(r#""user_" + i"#));
+
+    // JSON text is not an expression
+    assert!(!needs_evaluation(r#""a":1"#));
+    assert!(!needs_evaluation(r#" "tags": ["a", "b"], "n": -1"#));
+}
+
+#[test]
+fn test_render_json_text() {
+    let templating = start();
+    templating.add_variable("i", &3);
+
+    assert_eq!(
+        render(&templating, r#"INSERT INTO t (data) VALUES ('{"a":1}')"#)
+            .unwrap(),
+        r#"INSERT INTO t (data) VALUES ('{"a":1}')"#
+    );
+    assert_eq!(
+        render(
+            &templating,
+            r#"UPDATE t SET data = '{"n": 1}' WHERE id = {i + 1}"#
+        )
+        .unwrap(),
+        r#"UPDATE t SET data = '{"n": 1}' WHERE id = 4"#
+    );
+}
+
+#[test]
+fn test_evaluate_arithmetic() {
+    let templating = start();
+    templating.add_variable("i", &7);
+
+    assert_eq!(evaluate(&templating, "i * 2 + 1").unwrap(), "15");
+    assert_eq!(evaluate(&templating, "(i + 1) * 2").unwrap(), "16");
+    assert_eq!(evaluate(&templating, "i / 2").unwrap(), "3");
+    assert_eq!(evaluate(&templating, "i % 3").unwrap(), "1");
+    assert_eq!(evaluate(&templating, "i - 10").unwrap(), "-3");
+    assert_eq!(evaluate(&templating, "-i").unwrap(), "-7");
+    assert_eq!(evaluate(&templating, "i / 2.0").unwrap(), "3.5");
+    assert_eq!(evaluate(&templating, r#""user_" + i"#).unwrap(), "user_7");
+
+    assert!(evaluate(&templating, "i / 0").is_err());
+    assert!(evaluate(&templating, r#""a" * 2"#).is_err());
+    assert!(evaluate(&templating, "i +").is_err());
+    assert!(evaluate(&templating, "(i + 1").is_err());
+    assert!(evaluate(&templating, "missing + 1").is_err());
+}
+
+#[test]
+fn test_evaluate_comparisons() {
+    let templating = start();
+    templating.add_variable("i", &10);
+
+    assert_eq!(evaluate(&templating, "i % 10 == 0").unwrap(), "true");
+    assert_eq!(evaluate(&templating, "i != 10").unwrap(), "false");
+    assert_eq!(evaluate(&templating, "i >= 10 && i < 20").unwrap(), "true");
+    assert_eq!(evaluate(&templating, "i > 10 || !false").unwrap(), "true");
+    assert_eq!(evaluate(&templating, r#""b" > "a""#).unwrap(), "true");
+}
+
+#[test]
+fn test_if_then_else() {
+    assert_eq!(if_then_else(&args(&["true", "a", "b"])).unwrap(), "a");
+    assert_eq!(if_then_else(&args(&["false", "a", "b"])).unwrap(), "b");
+    assert_eq!(if_then_else(&args(&["0", "a", "b"])).unwrap(), "b");
+    assert_eq!(if_then_else(&args(&["1", "a", "b"])).unwrap(), "a");
+    assert!(if_then_else(&args(&["true", "a"])).is_err());
+}
+
+#[test]
+fn test_render_expressions() {
+    let templating = start();
+
+    for i in 0..20 {
+        templating.add_variable("i", &i);
+
+        let role =
+            render(&templating, r#"{if(i % 10 == 0, "admin", "client")}"#)
+                .unwrap();
+        assert_eq!(role, if i % 10 == 0 { "admin" } else { "client" });
+
+        let email =
+            render(&templating, "user_{i * 2}@{sha256(i)}.test").unwrap();
+        assert!(email.starts_with(&format!("user_{}@", i * 2)));
+    }
+
+    // Nested calls keep text with quotes and commas as is
+    assert_eq!(
+        render(&templating, r#"{if(1 < 2, "a, \"b\"", "c")}"#).unwrap(),
+        r#"a, "b""#
+    );
+
+    let plan = render(
+        &templating,
+        r#"{weighted("free":80, "pro":15, "enterprise":5)}"#,
+    )
+    .unwrap();
+    assert!(["free", "pro", "enterprise"].contains(&plan.as_str()));
+}
+
+#[test]
+fn test_choice() {
+    let options = args(&["draft", "published", "archived"]);
+
+    for _ in 0..50 {
+        assert!(options.contains(&choice(&options).unwrap()));
+    }
+    assert!(choice(&[]).is_err());
+}
+
+#[test]
+fn test_weighted() {
+    for _ in 0..50 {
+        assert_eq!(
+            weighted(&args(&["free:0", "pro:1", "enterprise:0"])).unwrap(),
+            "pro"
+        );
+    }
+
+    assert_eq!(weighted(&args(&["a:b:1"])).unwrap(), "a:b");
+    assert!(weighted(&args(&["free"])).is_err());
+    assert!(weighted(&args(&["free:-1"])).is_err());
+    assert!(weighted(&args(&["free:0"])).is_err());
+}
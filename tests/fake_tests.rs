@@ -1,4 +1,19 @@
+#![cfg(feature = "fake")]
+
 use grow_rs::commands::run::fake::fake;
+use grow_rs::commands::run::fake_generated::{
+    BOOLEAN, DATE_TIME_BETWEEN, DECIMAL, LICENCE_PLATE, LICENCE_PLATE_DE_DE,
+    NEGATIVE_DECIMAL, NO_DECIMAL_POINTS, PASSWORD, POSITIVE_DECIMAL, UUID_V4,
+    WORDS,
+};
+
+fn fake_with(kind: u16, args: &[&str]) -> Result<String, String> {
+    let args = std::iter::once(kind.to_string())
+        .chain(args.iter().map(|arg| arg.to_string()))
+        .collect::<Vec<_>>();
+
+    fake(&args).map_err(|err| format!("{err:?}"))
+}
 
 #[test]
 fn test_fake_function_with_valid_kind() {
@@ -112,6 +127,78 @@ fn test_fake_function_zero_kind() {
     }
 }
 
+#[test]
+fn test_fake_with_arguments() {
+    assert_eq!(fake_with(WORDS, &["3", "3"]).unwrap().split(' ').count(), 3);
+    assert_eq!(
+        fake_with(PASSWORD, &["12", "12"]).unwrap().chars().count(),
+        12
+    );
+    assert_eq!(fake_with(BOOLEAN, &["100"]).unwrap(), "true");
+    assert_eq!(fake_with(BOOLEAN, &["0"]).unwrap(), "false");
+
+    let datetime = fake_with(
+        DATE_TIME_BETWEEN,
+        &["2024-01-01 00:00:00", "2024-01-01 12:00:00"],
+    )
+    .unwrap();
+    assert!(datetime.starts_with("2024-01-01"), "{datetime}");
+}
+
+#[test]
+fn test_fake_with_invalid_arguments() {
+    let missing = fake_with(WORDS, &["3"]).unwrap_err();
+    assert!(
+        missing.contains("WORDS: Missing argument `count`"),
+        "{missing}"
+    );
+
+    let reversed = fake_with(WORDS, &["5", "2"]).unwrap_err();
+    assert!(reversed.contains("5 is greater than 2"), "{reversed}");
+
+    let extra = fake_with(UUID_V4, &["1"]).unwrap_err();
+    assert!(extra.contains("Too many arguments for UUID_V4"), "{extra}");
+}
+
+#[test]
+fn test_fake_single_locale_and_uuid_kinds() {
+    // Licence plates only exist in French, as `AB-123-CD`
+    for kind in [LICENCE_PLATE, LICENCE_PLATE_DE_DE] {
+        let plate = fake_with(kind, &[]).unwrap();
+        assert_eq!(plate.len(), 9, "{plate}");
+        assert_eq!(plate.matches('-').count(), 2, "{plate}");
+    }
+
+    assert!(uuid::Uuid::parse_str(&fake_with(UUID_V4, &[]).unwrap()).is_ok());
+}
+
+#[test]
+fn test_fake_decimal_kinds_are_bounded() {
+    for _ in 0..200 {
+        for kind in [
+            DECIMAL,
+            POSITIVE_DECIMAL,
+            NEGATIVE_DECIMAL,
+            NO_DECIMAL_POINTS,
+        ] {
+            let text = fake_with(kind, &[]).unwrap();
+            let value = text.parse::<f64>().unwrap();
+            let decimals = text.split_once('.').map_or(0, |(_, d)| d.len());
+
+            // Fits a `DECIMAL(12, 4)` column
+            assert!(value.abs() < 1e8, "{text}");
+            assert!(decimals <= 4, "{text}");
+
+            match kind {
+                POSITIVE_DECIMAL => assert!(value > 0.0, "{text}"),
+                NEGATIVE_DECIMAL => assert!(value < 0.0, "{text}"),
+                NO_DECIMAL_POINTS => assert_eq!(decimals, 0, "{text}"),
+                _ => {}
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
@@ -149,3 +236,46 @@ mod integration_tests {
         }
     }
 }
+
+#[test]
+fn test_fake_catalog() {
+    use grow_rs::commands::fake::sample_values;
+    use grow_rs::commands::run::fake::locale_index;
+    use grow_rs::commands::run::fake_generated::{
+        FAKERS, FIRST_NAME_JA_JP, LOCALES,
+    };
+
+    // Every faker is in the catalog once, in the order of its kinds
+    assert!(FAKERS
+        .windows(2)
+        .all(|pair| pair[1].kind == pair[0].kind + LOCALES.len() as u16));
+
+    let first_name = FAKERS.iter().find(|f| f.name == "FIRST_NAME").unwrap();
+    let japanese = locale_index("JA_JP");
+    assert_eq!(first_name.module, "name");
+    assert_eq!(first_name.name_in(japanese), "FIRST_NAME_JA_JP");
+    assert_eq!(first_name.kind_in(japanese), FIRST_NAME_JA_JP);
+
+    let words = FAKERS.iter().find(|f| f.name == "WORDS").unwrap();
+    assert_eq!(words.signature(), "fake(WORDS, min, max)");
+
+    let plate = FAKERS.iter().find(|f| f.name == "LICENCE_PLATE").unwrap();
+    assert_eq!(plate.only, Some("FR_FR"));
+
+    assert_eq!(sample_values("free_email", 3, &[]).unwrap().len(), 3);
+    assert_eq!(sample_values("FIRST_NAME_JA_JP", 1, &[]).unwrap().len(), 1);
+    assert_eq!(
+        sample_values("WORDS", 1, &["2".to_string(), "2".to_string()]).unwrap()
+            [0]
+        .split(' ')
+        .count(),
+        2
+    );
+
+    let missing = sample_values("WORDS", 1, &[]).unwrap_err();
+    assert!(
+        missing.ends_with("usage: fake(WORDS, min, max)"),
+        "{missing}"
+    );
+    assert!(sample_values("NOT_A_KIND", 1, &[]).is_err());
+}
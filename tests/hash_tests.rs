@@ -0,0 +1,59 @@
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::Argon2;
+use grow_rs::commands::run::hash::{argon2, bcrypt, sha256};
+use grow_rs::commands::run::template::{render, start};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_bcrypt_verifies() {
+    let hash = bcrypt(&args(&["secret", "4"])).unwrap();
+
+    assert!(hash.starts_with("$2b$04$"));
+    assert!(::bcrypt::verify("secret", &hash).unwrap());
+    assert!(!::bcrypt::verify("other", &hash).unwrap());
+}
+
+#[test]
+fn test_bcrypt_invalid_cost() {
+    assert!(bcrypt(&args(&["secret", "high"])).is_err());
+    assert!(bcrypt(&args(&["secret", "2"])).is_err());
+    assert!(bcrypt(&[]).is_err());
+}
+
+#[test]
+fn test_argon2_verifies() {
+    let hash = argon2(&args(&["secret"])).unwrap();
+    let parsed = PasswordHash::new(&hash).unwrap();
+
+    assert!(hash.starts_with("$argon2id$"));
+    assert!(Argon2::default()
+        .verify_password(b"secret", &parsed)
+        .is_ok());
+    assert_ne!(hash, argon2(&args(&["secret"])).unwrap());
+}
+
+#[test]
+fn test_sha256() {
+    assert_eq!(
+        sha256(&args(&["abc"])).unwrap(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert!(sha256(&args(&["a", "b"])).is_err());
+}
+
+#[test]
+fn test_render_hash_functions() {
+    let templating = start();
+    templating.add_variable("i", &3);
+
+    let hash = render(&templating, r#"{bcrypt("secret", 4)}"#).unwrap();
+    assert!(::bcrypt::verify("secret", &hash).unwrap());
+
+    assert_eq!(
+        render(&templating, "{sha256(i)}").unwrap(),
+        sha256(&args(&["3"])).unwrap()
+    );
+}
@@ -0,0 +1,66 @@
+use grow_rs::commands::run::identifier::{
+    nanoid, ulid, uuid, uuid_v5, uuid_v7,
+};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_uuid_v4() {
+    let value = uuid(&[]).unwrap();
+    let parsed = ::uuid::Uuid::parse_str(&value).unwrap();
+
+    assert_eq!(parsed.get_version_num(), 4);
+    assert_ne!(value, uuid(&[]).unwrap());
+    assert!(uuid(&args(&["1"])).is_err());
+}
+
+#[test]
+fn test_uuid_v7_is_time_ordered() {
+    let first = uuid_v7(&[]).unwrap();
+    let second = uuid_v7(&[]).unwrap();
+
+    let parsed = ::uuid::Uuid::parse_str(&first).unwrap();
+    assert_eq!(parsed.get_version_num(), 7);
+    assert!(first < second);
+}
+
+#[test]
+fn test_uuid_v5_is_deterministic() {
+    let value = uuid_v5(&args(&["dns", "example.com"])).unwrap();
+
+    assert_eq!(value, "cfbff0d1-9375-5685-968c-48ce8b15ae17");
+    assert_eq!(value, uuid_v5(&args(&["DNS", "example.com"])).unwrap());
+
+    let custom = uuid_v5(&args(&[&value, "admin"])).unwrap();
+    assert_eq!(custom, uuid_v5(&args(&[&value, "admin"])).unwrap());
+    assert_ne!(custom, uuid_v5(&args(&[&value, "client"])).unwrap());
+}
+
+#[test]
+fn test_uuid_v5_invalid_namespace() {
+    assert!(uuid_v5(&args(&["users", "admin"])).is_err());
+    assert!(uuid_v5(&args(&["dns"])).is_err());
+}
+
+#[test]
+fn test_ulid() {
+    let value = ulid(&[]).unwrap();
+
+    assert_eq!(value.len(), 26);
+    assert!(::ulid::Ulid::from_string(&value).is_ok());
+}
+
+#[test]
+fn test_nanoid() {
+    assert_eq!(nanoid(&[]).unwrap().len(), 21);
+    assert_eq!(nanoid(&args(&["10"])).unwrap().len(), 10);
+
+    let value = nanoid(&args(&["8", "ab"])).unwrap();
+    assert_eq!(value.len(), 8);
+    assert!(value.chars().all(|c| c == 'a' || c == 'b'));
+
+    assert!(nanoid(&args(&["ten"])).is_err());
+    assert!(nanoid(&args(&["8", ""])).is_err());
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use common::{render_statements, seeders_folder};
+use std::path::Path;
+
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::seeder_format::is_partial;
+use grow_rs::{SqlValue, Statement};
+
+async fn inserted_rows(seeder: Seeder) -> Vec<(String, String)> {
+    render_statements(seeder, "mock://")
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|statement| match statement {
+            Statement::Insert { table, row } => {
+                let name = row
+                    .into_iter()
+                    .find(|(column, _)| column == "name")
+                    .map(|(_, value)| value);
+                match name {
+                    Some(SqlValue::Text(name)) => Some((table, name)),
+                    _ => None,
+                }
+            }
+            Statement::Sql(_) => None,
+        })
+        .collect()
+}
+
+const BASE_ROLES: &str = r#"
+#![vars(role: "admin")]
+
+{
+    roles: [(name: "{role}")],
+}
+"#;
+
+#[test]
+fn test_is_partial() {
+    assert!(is_partial(Path::new("_shared/base_roles.ron")));
+    assert!(is_partial(Path::new("_base.ron")));
+    assert!(is_partial(Path::new("shared/_fragments/roles.ron")));
+    assert!(!is_partial(Path::new("shared/base_roles.ron")));
+    assert!(!is_partial(Path::new("users.ron")));
+}
+
+#[tokio::test]
+async fn test_include_ron() {
+    let root = seeders_folder(&[
+        ("_shared/base_roles.ron", BASE_ROLES),
+        (
+            "users.ron",
+            r#"
+#![include("_shared/base_roles.ron")]
+#![vars(role: "owner", user: "ana")]
+
+{
+    users: [(name: "{user} the {role}")],
+}
+"#,
+        ),
+    ]);
+
+    let seeder =
+        Seeder::get_from_root(&root, Some(&"users".to_owned())).unwrap();
+
+    // Included entries go first and the file overrides included variables
+    assert_eq!(
+        inserted_rows(seeder).await,
+        vec![
+            ("roles".to_owned(), "owner".to_owned()),
+            ("users".to_owned(), "ana the owner".to_owned()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_include_nested_and_other_formats() {
+    let root = seeders_folder(&[
+            ("_shared/base_roles.ron", BASE_ROLES),
+            (
+                "_shared/permissions.yaml",
+                "grow:\n  include: _shared/base_roles\npermissions:\n  - name: read\n",
+            ),
+            (
+                "users.json",
+                r#"{
+                    "grow": { "include": ["_shared/permissions.yaml"] },
+                    "users": [{ "name": "ana" }]
+                }"#,
+            ),
+        ],
+    );
+
+    let seeder =
+        Seeder::get_from_root(&root, Some(&"users".to_owned())).unwrap();
+
+    assert_eq!(
+        inserted_rows(seeder).await,
+        vec![
+            ("roles".to_owned(), "admin".to_owned()),
+            ("permissions".to_owned(), "read".to_owned()),
+            ("users".to_owned(), "ana".to_owned()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_folder_skips_partials() {
+    let root = seeders_folder(&[
+        ("_base.ron", "{ base: [(name: \"base\")] }"),
+        ("_shared/roles.ron", "{ roles: [(name: \"admin\")] }"),
+        ("users.ron", "{ users: [(name: \"ana\")] }"),
+    ]);
+
+    let seeder = Seeder::get_from_root(&root, None).unwrap();
+
+    assert_eq!(
+        inserted_rows(seeder).await,
+        vec![("users".to_owned(), "ana".to_owned())]
+    );
+}
+
+#[test]
+fn test_include_errors() {
+    let root = seeders_folder(&[
+        ("_a.ron", "#![include(\"_b.ron\")]\n{}"),
+        ("_b.ron", "#![include(\"_a.ron\")]\n{}"),
+        ("cycle.ron", "#![include(\"_a.ron\")]\n{}"),
+        ("itself.ron", "#![include(\"itself\")]\n{}"),
+        ("missing.ron", "#![include(\"_missing.ron\")]\n{}"),
+        ("number.ron", "#![include(10)]\n{}"),
+        ("number.yaml", "grow:\n  include: [10]\n"),
+    ]);
+    let load = |name: &str| {
+        Seeder::get_from_root(&root, Some(&name.to_owned())).unwrap_err()
+    };
+
+    assert_eq!(load("cycle"), "Include cycle: _a.ron -> _b.ron -> _a.ron");
+    assert_eq!(load("itself"), "Include cycle: itself.ron -> itself.ron");
+    assert!(load("missing.ron").contains("not found"));
+    assert!(load("number.ron")
+        .starts_with("Expect seeder paths in #![include(...)]"));
+    assert!(load("number.yaml").starts_with("Expect seeder paths in include"));
+}
+
+#[tokio::test]
+async fn test_include_templates() {
+    let root = seeders_folder(&[
+        (
+            "_shared/templates.ron",
+            "#![templates(base_user: (name: \"guest\", role: \"user\"))]\n{}",
+        ),
+        (
+            "users.ron",
+            r#"
+#![include("_shared/templates")]
+
+{
+    #[template = "base_user"] users: [(role: "admin")],
+}
+"#,
+        ),
+    ]);
+
+    let seeder =
+        Seeder::get_from_root(&root, Some(&"users".to_owned())).unwrap();
+
+    assert_eq!(
+        inserted_rows(seeder).await,
+        vec![("users".to_owned(), "guest".to_owned())]
+    );
+}
+
+#[tokio::test]
+async fn test_include_diamond_once() {
+    let root = seeders_folder(&[
+        ("_d.ron", "{ roles: [(name: \"admin\")] }"),
+        ("_b.ron", "#![include(\"_d\")]\n{ teams: [(name: \"b\")] }"),
+        ("_c.ron", "#![include(\"_d\")]\n{ teams: [(name: \"c\")] }"),
+        (
+            "a.ron",
+            "#![include(\"_b\", \"_c\")]\n{ users: [(name: \"ana\")] }",
+        ),
+    ]);
+
+    let seeder = Seeder::get_from_root(&root, Some(&"a".to_owned())).unwrap();
+
+    assert_eq!(
+        inserted_rows(seeder).await,
+        vec![
+            ("roles".to_owned(), "admin".to_owned()),
+            ("teams".to_owned(), "b".to_owned()),
+            ("teams".to_owned(), "c".to_owned()),
+            ("users".to_owned(), "ana".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_include_run_attributes() {
+    let root = seeders_folder(&[
+        ("_env.ron", "#![env(\"dev\")]\n{}"),
+        ("_requires.ron", "#![requires(\"roles\")]\n{}"),
+        ("_connection.yaml", "grow:\n  connection: analytics\n"),
+        ("env.ron", "#![include(\"_env\")]\n{}"),
+        ("requires.ron", "#![include(\"_requires\")]\n{}"),
+        ("connection.ron", "#![include(\"_connection\")]\n{}"),
+    ]);
+    let load = |name: &str| {
+        Seeder::get_from_root(&root, Some(&name.to_owned())).unwrap_err()
+    };
+    let error = |file: &str| {
+        format!(
+            "The included seeder {file} cannot set env, requires or \
+            connection, set them on the seeder including it"
+        )
+    };
+
+    assert_eq!(load("env"), error("_env.ron"));
+    assert_eq!(load("requires"), error("_requires.ron"));
+    assert_eq!(load("connection"), error("_connection.yaml"));
+}
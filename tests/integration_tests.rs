@@ -1,5 +1,6 @@
 use grow_rs::SqlValue;
 
+#[allow(clippy::approx_constant)]
 #[test]
 fn test_sql_value_re_export() {
     // Test that SqlValue is properly re-exported from grow_core
@@ -16,6 +17,7 @@ fn test_sql_value_re_export() {
     assert_eq!(null_val, SqlValue::Null);
 }
 
+#[allow(clippy::approx_constant)]
 #[test]
 fn test_sql_value_from_conversions() {
     // Test From trait implementations work through re-export
@@ -88,23 +90,4 @@ mod module_visibility_tests {
         let templating = commands::run::template::start();
         assert!(templating.render("simple text").is_ok());
     }
-
-    #[test]
-    fn test_rendered_table_type_accessible() {
-        // Test that RenderedTable type is accessible
-        let mut table: commands::run::template::RenderedTable = Vec::new();
-
-        let row = vec![
-            ("id".to_string(), grow_rs::SqlValue::Integer(1)),
-            (
-                "name".to_string(),
-                grow_rs::SqlValue::Text("Test".to_string()),
-            ),
-        ];
-
-        table.push(row);
-
-        assert_eq!(table.len(), 1);
-        assert_eq!(table[0].len(), 2);
-    }
 }
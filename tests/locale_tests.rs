@@ -0,0 +1,91 @@
+#![cfg(feature = "fake")]
+
+mod common;
+
+use common::{render_statements, render_tables};
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::fake::{
+    fake, locale_index, set_entry_locale, set_run_locale,
+};
+use grow_rs::commands::run::fake_generated::{
+    FIRST_NAME, FIRST_NAME_JA_JP, LOCALES,
+};
+use grow_rs::{SqlValue, Statement};
+
+fn first_name(kind: u16) -> String {
+    fake(&[kind.to_string()]).unwrap()
+}
+
+fn is_japanese(text: &str) -> bool {
+    !text.is_ascii()
+}
+
+#[test]
+fn test_locale_index() {
+    assert_eq!(LOCALES[locale_index("EN")].1, "EN");
+    assert_eq!(LOCALES[locale_index("PT_BR")].1, "PT_BR");
+    assert_eq!(LOCALES[locale_index("ja-jp")].1, "JA_JP");
+    // Unknown locales fall back to English
+    assert_eq!(locale_index("XX_YY"), 0);
+}
+
+// The locales are global, so every change happens in this test
+#[tokio::test]
+async fn test_locales() {
+    assert!(!is_japanese(&first_name(FIRST_NAME)));
+
+    set_run_locale("JA_JP");
+    assert!(is_japanese(&first_name(FIRST_NAME)));
+
+    set_entry_locale(Some("EN"));
+    assert!(!is_japanese(&first_name(FIRST_NAME)));
+    // Explicit locales are kept
+    assert!(is_japanese(&first_name(FIRST_NAME_JA_JP)));
+
+    set_entry_locale(None);
+    set_run_locale("EN");
+    assert!(!is_japanese(&first_name(FIRST_NAME)));
+
+    let seeder = Seeder::parse(
+        r#"{
+    #[repeat = 3]
+    #[locale = "JA_JP"]
+    japanese: (name: "{fake(FIRST_NAME)}"),
+
+    #[repeat = 3]
+    english: (name: "{fake(FIRST_NAME)}"),
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let tables = render_tables(seeder, "mock://").await.unwrap();
+
+    for (table, japanese) in [("japanese", true), ("english", false)] {
+        for row in &tables[table] {
+            let SqlValue::Text(name) = &row[0].1 else {
+                panic!("Expected text in {table}");
+            };
+            assert_eq!(is_japanese(name), japanese, "{table}: {name}");
+        }
+    }
+
+    // Raw SQL after an entry with a locale uses the locale of the run
+    let seeder = Seeder::parse(
+        r#"{
+    #[locale = "JA_JP"]
+    japanese: [(name: "{fake(FIRST_NAME)}")],
+
+    #[sql]
+    _: "INSERT INTO english (name) VALUES ('{fake(FIRST_NAME)}')",
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let statements = render_statements(seeder, "mock://").await.unwrap();
+    let Some(Statement::Sql(sql)) = statements.last() else {
+        panic!("Expected raw SQL");
+    };
+    assert!(!is_japanese(sql), "{sql}");
+}
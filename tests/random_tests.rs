@@ -0,0 +1,72 @@
+#![cfg(feature = "fake")]
+
+use grow_rs::commands::run::datetime::parse_timestamp;
+use grow_rs::commands::run::random::{
+    date_between, datetime_past, rand_float, rand_int,
+};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_rand_int_in_range() {
+    for _ in 0..100 {
+        let value = rand_int(&args(&["-5", "5"])).unwrap();
+        let value = value.parse::<i64>().unwrap();
+        assert!((-5..=5).contains(&value));
+    }
+}
+
+#[test]
+fn test_rand_int_invalid_arguments() {
+    assert!(rand_int(&args(&["1"])).is_err());
+    assert!(rand_int(&args(&["a", "5"])).is_err());
+    assert!(rand_int(&args(&["10", "1"])).is_err());
+}
+
+#[test]
+fn test_rand_float_decimals() {
+    for _ in 0..100 {
+        let value = rand_float(&args(&["0.0", "99.99", "2"])).unwrap();
+        let (_, decimals) = value.split_once('.').unwrap();
+        assert_eq!(decimals.len(), 2);
+
+        let value = value.parse::<f64>().unwrap();
+        assert!((0.0..=99.99).contains(&value));
+    }
+}
+
+#[test]
+fn test_rand_float_without_decimals() {
+    let value = rand_float(&args(&["1.5", "1.5"])).unwrap();
+    assert_eq!(value, "1.5");
+}
+
+#[test]
+fn test_date_between_is_inclusive() {
+    for _ in 0..50 {
+        let value = date_between(&args(&["2024-01-01", "2024-01-02"])).unwrap();
+        assert!(value == "2024-01-01" || value == "2024-01-02");
+    }
+
+    let value = date_between(&args(&["2024-02-29", "2024-02-29"])).unwrap();
+    assert_eq!(value, "2024-02-29");
+}
+
+#[test]
+fn test_date_between_rejects_reversed_range() {
+    assert!(date_between(&args(&["2024-12-31", "2023-01-01"])).is_err());
+    assert!(date_between(&args(&["yesterday", "2023-01-01"])).is_err());
+}
+
+#[test]
+fn test_datetime_past() {
+    let now = chrono::Utc::now().naive_utc();
+    let value = datetime_past(&args(&["30d"])).unwrap();
+    let value = parse_timestamp(&value).unwrap();
+
+    assert!(value <= now);
+    assert!(value >= now - chrono::Duration::days(30));
+    assert!(datetime_past(&args(&["-7d"])).is_err());
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::seeders_folder;
+
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::requirements::{order, plan};
+use grow_rs::commands::run::seeder_format::SeederFormat;
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_parse_requires() {
+    let ron = Seeder::parse(
+        r#"
+#![requires("1700000000_roles", "catalogs/1600000000_countries.ron")]
+
+{ users: [(name: "ana")] }
+"#
+        .to_owned(),
+    )
+    .unwrap();
+    assert_eq!(
+        ron.requires,
+        names(&["1700000000_roles", "catalogs/1600000000_countries.ron"])
+    );
+
+    let toml = SeederFormat::Toml
+        .parse("[grow]\nrequires = \"1700000000_roles\"\n".to_owned())
+        .unwrap();
+    assert_eq!(toml.requires, names(&["1700000000_roles"]));
+}
+
+#[test]
+fn test_plan() {
+    let root = seeders_folder(&[
+        ("1_roles.ron", "{}"),
+        ("catalogs/2_countries.yaml", "grow:\n  requires: 1_roles\n"),
+        (
+            "3_users.ron",
+            "#![requires(\"catalogs/2_countries.yaml\", \"1_roles\")]\n{}",
+        ),
+        ("4_orders.ron", "#![requires(\"3_users\")]\n{}"),
+    ]);
+
+    assert_eq!(plan(&root, "1_roles").unwrap(), names(&["1_roles"]));
+    assert_eq!(
+        plan(&root, "4_orders").unwrap(),
+        names(&["1_roles", "catalogs/2_countries", "3_users", "4_orders"])
+    );
+}
+
+#[test]
+fn test_plan_errors() {
+    let root = seeders_folder(&[
+        ("1_a.ron", "#![requires(\"2_b\")]\n{}"),
+        ("2_b.ron", "#![requires(\"3_c\")]\n{}"),
+        ("3_c.ron", "#![requires(\"1_a\")]\n{}"),
+        ("4_d.ron", "#![requires(\"9_missing\")]\n{}"),
+    ]);
+
+    assert_eq!(
+        plan(&root, "1_a").unwrap_err(),
+        "Requirement cycle: 1_a -> 2_b -> 3_c -> 1_a"
+    );
+    assert_eq!(
+        plan(&root, "4_d").unwrap_err(),
+        "4_d requires 9_missing, which is not a seeder"
+    );
+}
+
+#[test]
+fn test_order() {
+    let root = seeders_folder(&[
+        ("1_users.ron", "#![requires(\"3_roles\")]\n{}"),
+        ("2_countries.ron", "{}"),
+        ("3_roles.ron", "#![requires(\"0_executed\")]\n{}"),
+        ("4_orders.ron", "#![requires(\"1_users\")]\n{}"),
+    ]);
+
+    // Requirements out of the list, as executed seeders, are ignored
+    assert_eq!(
+        order(
+            &root,
+            names(&["1_users", "2_countries", "3_roles", "4_orders"])
+        )
+        .unwrap(),
+        names(&["2_countries", "3_roles", "1_users", "4_orders"])
+    );
+    assert_eq!(
+        order(&root, names(&["2_countries", "4_orders"])).unwrap(),
+        names(&["2_countries", "4_orders"])
+    );
+}
+
+#[test]
+fn test_order_cycle() {
+    let root = seeders_folder(&[
+        ("1_a.ron", "#![requires(\"2_b\")]\n{}"),
+        ("2_b.ron", "#![requires(\"1_a\")]\n{}"),
+        ("3_c.ron", "{}"),
+    ]);
+
+    assert_eq!(
+        order(&root, names(&["1_a", "2_b", "3_c"])).unwrap_err(),
+        "Requirement cycle between 1_a, 2_b"
+    );
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::render_tables;
+use grow_rs::commands::run::entry::Seeder;
+use grow_rs::commands::run::seeder_format::SeederFormat;
+use grow_rs::SqlValue;
+
+const MOCK_DB: &str = "mock://";
+
+fn text(value: &str) -> SqlValue {
+    SqlValue::Text(value.to_owned())
+}
+
+#[tokio::test]
+async fn test_template_static_and_repeat() {
+    let seeder = Seeder::parse(
+        r#"
+#![templates(base_user: (role: "user", active: 1, country: "AR"))]
+
+{
+    #[template = "base_user"] users: [
+        (email: "ana@grow.dev"),
+        (email: "bob@grow.dev", role: "admin"),
+    ],
+
+    #[repeat = 2]
+    #[template = "base_user"]
+    guests: {
+        "email": "guest_{i}@grow.dev",
+        "country": "UY",
+    },
+}
+"#
+        .to_owned(),
+    )
+    .unwrap();
+
+    let tables = render_tables(seeder, MOCK_DB).await.unwrap();
+
+    assert_eq!(
+        tables["users"],
+        vec![
+            vec![
+                ("active".to_owned(), SqlValue::Integer(1)),
+                ("country".to_owned(), text("AR")),
+                ("email".to_owned(), text("ana@grow.dev")),
+                ("role".to_owned(), text("user")),
+            ],
+            vec![
+                ("active".to_owned(), SqlValue::Integer(1)),
+                ("country".to_owned(), text("AR")),
+                ("email".to_owned(), text("bob@grow.dev")),
+                ("role".to_owned(), text("admin")),
+            ],
+        ]
+    );
+    assert_eq!(
+        tables["guests"][1],
+        vec![
+            ("active".to_owned(), SqlValue::Integer(1)),
+            ("country".to_owned(), text("UY")),
+            ("email".to_owned(), text("guest_1@grow.dev")),
+            ("role".to_owned(), text("user")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_template_yaml() {
+    let seeder = SeederFormat::Yaml
+        .parse(
+            r#"
+grow:
+  templates:
+    base_user:
+      role: user
+      active: 1
+
+users:
+  template: base_user
+  values:
+    - email: ana@grow.dev
+"#
+            .to_owned(),
+        )
+        .unwrap();
+
+    let tables = render_tables(seeder, MOCK_DB).await.unwrap();
+
+    assert_eq!(
+        tables["users"],
+        vec![vec![
+            ("active".to_owned(), SqlValue::Integer(1)),
+            ("email".to_owned(), text("ana@grow.dev")),
+            ("role".to_owned(), text("user")),
+        ]]
+    );
+}
+
+#[tokio::test]
+async fn test_template_errors() {
+    let unknown = Seeder::parse(
+        r#"{ #[template = "missing"] users: [(email: "a@grow.dev")] }"#
+            .to_owned(),
+    )
+    .unwrap();
+    assert_eq!(
+        render_tables(unknown, MOCK_DB).await.unwrap_err(),
+        "Unknown template `missing` in users"
+    );
+
+    let csv = Seeder::parse(
+        r#"{ #[template = "base"] #[csv = "users.csv"] users: () }"#.to_owned(),
+    );
+    assert_eq!(
+        csv.unwrap_err(),
+        "#[csv] cannot be used with #[template] in users"
+    );
+
+    let invalid = Seeder::parse(r#"#![templates(base: 1)] {}"#.to_owned());
+    assert!(invalid.unwrap_err().ends_with("in template `base`"));
+}
@@ -0,0 +1,252 @@
+mod common;
+
+use common::render_tables;
+use std::path::Path;
+
+use grow_rs::commands::run::entry::Entry;
+use grow_rs::commands::run::seeder_format::{
+    seeder_name, seeder_path, SeederFormat,
+};
+use grow_rs::SqlValue;
+
+const MOCK_DB: &str = "mock://";
+
+const RON: &str = r#"
+#![vars(domain: "grow.dev")]
+
+{
+    #[schema = "auth"] roles: [
+        (name: "admin", level: 10),
+        (name: "guest", level: 0),
+    ],
+
+    #[repeat = 2] users: {
+        "email": "user_{i}@{domain}",
+        "age": 30,
+    },
+}
+"#;
+
+const YAML: &str = r#"
+grow:
+  vars:
+    domain: grow.dev
+
+roles:
+  schema: auth
+  values:
+    - name: admin
+      level: 10
+    - name: guest
+      level: 0
+
+users:
+  repeat: 2
+  fields:
+    email: "user_{i}@{domain}"
+    age: 30
+"#;
+
+const JSON: &str = r#"{
+    "grow": { "vars": { "domain": "grow.dev" } },
+    "roles": {
+        "schema": "auth",
+        "values": [
+            { "name": "admin", "level": 10 },
+            { "name": "guest", "level": 0 }
+        ]
+    },
+    "users": {
+        "repeat": 2,
+        "fields": { "email": "user_{i}@{domain}", "age": 30 }
+    }
+}"#;
+
+const TOML: &str = r#"
+[grow.vars]
+domain = "grow.dev"
+
+[roles]
+schema = "auth"
+values = [
+    { name = "admin", level = 10 },
+    { name = "guest", level = 0 },
+]
+
+[users]
+repeat = 2
+fields = { email = "user_{i}@{domain}", age = 30 }
+"#;
+
+#[test]
+fn test_seeder_format_from_path() {
+    let format = |path: &str| SeederFormat::from_path(Path::new(path));
+
+    assert_eq!(format("1_users.ron"), Some(SeederFormat::Ron));
+    assert_eq!(format("1_users.yaml"), Some(SeederFormat::Yaml));
+    assert_eq!(format("1_users.yml"), Some(SeederFormat::Yaml));
+    assert_eq!(format("1_users.json"), Some(SeederFormat::Json));
+    assert_eq!(format("1_users.toml"), Some(SeederFormat::Toml));
+    assert_eq!(format("1_users.sql"), Some(SeederFormat::Sql));
+    assert_eq!(format("1_users.csv"), None);
+    assert_eq!(format("users"), None);
+}
+
+#[test]
+fn test_seeder_name_and_path() {
+    assert_eq!(seeder_name("1_users.yaml"), "1_users");
+    assert_eq!(seeder_name("1_users.ron"), "1_users");
+    assert_eq!(seeder_name("1_users"), "1_users");
+    assert_eq!(seeder_name("1_users.v2"), "1_users.v2");
+
+    let folder = std::env::temp_dir().join("grow_seeder_formats");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(folder.join("2_posts.toml"), TOML).unwrap();
+
+    assert_eq!(seeder_path(&folder, "2_posts"), folder.join("2_posts.toml"));
+    assert_eq!(
+        seeder_path(&folder, "2_posts.yaml"),
+        folder.join("2_posts.yaml")
+    );
+    // Missing seeders keep the RON file, so the error names it
+    assert_eq!(seeder_path(&folder, "3_tags"), folder.join("3_tags.ron"));
+}
+
+#[tokio::test]
+async fn test_formats_render_the_same_tables() {
+    let parse = |format: SeederFormat, content: &str| {
+        format.parse(content.to_string()).unwrap()
+    };
+
+    let expected = render_tables(parse(SeederFormat::Ron, RON), MOCK_DB)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        expected["auth.roles"][0],
+        vec![
+            ("level".to_string(), SqlValue::Integer(10)),
+            ("name".to_string(), SqlValue::Text("admin".to_string())),
+        ]
+    );
+    assert_eq!(
+        expected["users"][1][1].1,
+        SqlValue::Text("user_1@grow.dev".to_string())
+    );
+
+    for (format, content) in [
+        (SeederFormat::Yaml, YAML),
+        (SeederFormat::Json, JSON),
+        (SeederFormat::Toml, TOML),
+    ] {
+        let tables = render_tables(parse(format, content), MOCK_DB)
+            .await
+            .unwrap();
+
+        assert_eq!(tables, expected, "{format:?}");
+    }
+}
+
+#[test]
+fn test_format_entries() {
+    let seeder = SeederFormat::Yaml
+        .parse(
+            r#"
+countries:
+  csv: data/countries.csv
+  render: true
+  unique: [code, name]
+  reset_sequence: false
+settings:
+  - key: theme
+    value: { dark: true }
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+    let Entry::File {
+        render, options, ..
+    } = &seeder.entries[0]
+    else {
+        panic!("Expected a file entry");
+    };
+    assert!(render);
+    assert_eq!(options.unique, ["code", "name"]);
+    assert!(options.keep_sequence);
+
+    let Entry::Static { values, .. } = &seeder.entries[1] else {
+        panic!("Expected a static entry");
+    };
+    assert_eq!(
+        values[0]["value"],
+        SqlValue::Json(r#"{"dark":true}"#.to_string())
+    );
+}
+
+#[test]
+fn test_format_tables_named_as_attributes() {
+    let seeder = SeederFormat::Yaml
+        .parse(
+            r#"
+grow:
+  env: dev
+templates:
+  - name: invoice
+vars:
+  - key: tax
+    value: 21
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+    assert_eq!(seeder.environments, ["dev"]);
+    assert!(seeder.templates.is_empty() && seeder.vars.is_empty());
+
+    let tables = seeder
+        .entries
+        .iter()
+        .map(|entry| match entry {
+            Entry::Static { table_name, .. } => table_name.as_str(),
+            _ => panic!("Expected a static entry"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(tables, ["templates", "vars"]);
+}
+
+#[test]
+fn test_format_errors() {
+    let parse = |content: &str| {
+        SeederFormat::Yaml.parse(content.to_string()).unwrap_err()
+    };
+
+    let err = parse("users:\n  repeat: 2\n  fileds: { name: a }\n");
+    assert!(err.contains("Unknown attribute `fileds` in users"), "{err}");
+
+    let err = parse("users:\n  fields: { name: a }\n");
+    assert!(err.contains("`fields` need `repeat` in users"), "{err}");
+
+    let err = parse("users:\n  repeat: 2\n  csv: users.csv\n");
+    assert!(
+        err.contains("#[csv] cannot be used with #[repeat]"),
+        "{err}"
+    );
+
+    let err = parse("users:\n  repeat: many\n  fields: { name: a }\n");
+    assert!(err.contains("Invalid `repeat` in users"), "{err}");
+
+    let err = parse("- users\n");
+    assert_eq!(err, "Expect map");
+
+    let err = parse("grow:\n  envs: dev\n");
+    assert_eq!(err, "Unknown file attribute `envs` in grow");
+
+    let err = parse("grow: [dev]\n");
+    assert_eq!(err, "Expect map of file attributes in grow");
+
+    let err = SeederFormat::Toml
+        .parse("users = [".to_string())
+        .unwrap_err();
+    assert!(err.starts_with("Cannot parse seeder"), "{err}");
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::render_tables;
+use grow_rs::commands::run::entry::{Entry, EntryOptions};
+use grow_rs::commands::run::sequence::{resume_sequences, seq};
+use grow_rs::SqlValue;
+use std::collections::BTreeMap;
+
+// Sequences are global to the run, so every test uses its own names
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_seq_counts_from_start() {
+    assert_eq!(seq(&args(&["invoices", "1000"])).unwrap(), "1000");
+    assert_eq!(seq(&args(&["invoices", "1000"])).unwrap(), "1001");
+    // The start only applies to the first call
+    assert_eq!(seq(&args(&["invoices", "5"])).unwrap(), "1002");
+
+    assert_eq!(seq(&args(&["tickets"])).unwrap(), "1");
+    assert_eq!(seq(&args(&["tickets"])).unwrap(), "2");
+}
+
+#[test]
+fn test_seq_invalid_arguments() {
+    assert!(seq(&[]).is_err());
+    assert!(seq(&args(&[" "])).is_err());
+    assert!(seq(&args(&["coupons", "ten"])).is_err());
+}
+
+#[tokio::test]
+async fn test_seq_persists_across_entries() {
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "number".to_string(),
+        SqlValue::Text("{seq(order_number, 1000)}".to_string()),
+    );
+
+    let entries = vec![
+        Entry::Repeat {
+            count: 2,
+            table_name: "orders".to_string(),
+            fields: fields.clone(),
+            options: EntryOptions::default(),
+        },
+        Entry::Static {
+            table_name: "orders".to_string(),
+            values: vec![fields],
+            options: EntryOptions::default(),
+        },
+    ];
+
+    let tables = render_tables(entries, "mock://").await.unwrap();
+    let numbers: Vec<_> =
+        tables["orders"].iter().map(|row| &row[0].1).collect();
+
+    assert_eq!(
+        numbers,
+        [
+            &SqlValue::Integer(1000),
+            &SqlValue::Integer(1001),
+            &SqlValue::Integer(1002)
+        ]
+    );
+}
+
+#[cfg(feature = "sqlx")]
+#[tokio::test]
+async fn test_seq_resumes_from_column_max() {
+    let path = std::env::temp_dir()
+        .join(format!("grow_sequence_{}.db", std::process::id()));
+    let database_url = format!("sqlite://{}?mode=rwc", path.display());
+
+    for query in [
+        "CREATE TABLE orders (number INTEGER)",
+        "INSERT INTO orders VALUES (1500), (1499)",
+        "CREATE TABLE refunds (number INTEGER)",
+        r#"CREATE TABLE "order" ("group" INTEGER)"#,
+        r#"INSERT INTO "order" VALUES (7)"#,
+    ] {
+        grow_sqlx::execute_query(database_url.clone(), query)
+            .await
+            .unwrap();
+    }
+
+    let text = r#"{seq(resumed, 1000, "orders.number")} {seq(empty, 10, "refunds.number")}"#;
+    resume_sequences(text, &database_url).await.unwrap();
+
+    assert_eq!(seq(&args(&["resumed", "1000"])).unwrap(), "1501");
+    assert_eq!(seq(&args(&["empty", "10"])).unwrap(), "10");
+
+    // Reserved words are quoted like the inserted names
+    let reserved = r#"{seq(grouped, 1, "order.group")}"#;
+    resume_sequences(reserved, &database_url).await.unwrap();
+    assert_eq!(seq(&args(&["grouped", "1"])).unwrap(), "8");
+
+    let missing = r#"{seq(missing, 1, "nowhere.number")}"#;
+    assert!(resume_sequences(missing, &database_url).await.is_err());
+
+    let _ = std::fs::remove_file(path);
+}
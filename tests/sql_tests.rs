@@ -0,0 +1,155 @@
+mod common;
+
+use common::render_statements;
+use grow_rs::commands::run::entry::Entry;
+use grow_rs::commands::run::seeder_format::SeederFormat;
+use grow_rs::commands::run::sql::split_statements;
+use grow_rs::{SqlValue, Statement};
+
+#[test]
+fn test_split_statements() {
+    let statements = split_statements(
+        "-- roles { not a template }\n\
+         INSERT INTO roles (name) VALUES ('admin;root');\n\
+         /* users */ UPDATE users SET name = 'O''Brien; Jr' WHERE id = 1;\n\
+         SELECT \"weird;column\" FROM t;;\n\
+         CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql;\n\
+         SELECT $1",
+    );
+
+    assert_eq!(
+        statements,
+        vec![
+            "INSERT INTO roles (name) VALUES ('admin;root')",
+            "UPDATE users SET name = 'O''Brien; Jr' WHERE id = 1",
+            "SELECT \"weird;column\" FROM t",
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql",
+            "SELECT $1",
+        ]
+    );
+}
+
+#[test]
+fn test_split_statements_without_statements() {
+    assert!(split_statements("").is_empty());
+    assert!(split_statements("-- nothing to run\n;\n").is_empty());
+}
+
+#[tokio::test]
+async fn test_render_sql_seeder() {
+    std::env::set_var("GROW_SQL_ROLE", "editor");
+
+    let seeder = SeederFormat::Sql
+        .parse(
+            "INSERT INTO roles (name) VALUES ('{env(GROW_SQL_ROLE)}');\n\
+             UPDATE roles SET level = 1;"
+                .to_string(),
+        )
+        .unwrap();
+
+    assert!(matches!(seeder.entries[0], Entry::Sql { .. }));
+
+    let statements = render_statements(seeder, "mock://").await.unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            Statement::Sql(
+                "INSERT INTO roles (name) VALUES ('editor')".to_string()
+            ),
+            Statement::Sql("UPDATE roles SET level = 1".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_render_statements_in_source_order() {
+    let seeder = SeederFormat::Ron
+        .parse(
+            r#"{
+                users: [(name: "Alice")],
+                roles: [(name: "admin")],
+            }"#
+            .to_string(),
+        )
+        .unwrap();
+
+    let statements = render_statements(seeder, "mock://").await.unwrap();
+
+    let tables: Vec<_> = statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Insert { table, row } => (table.as_str(), &row[0].1),
+            Statement::Sql(sql) => panic!("Unexpected SQL {sql}"),
+        })
+        .collect();
+
+    assert_eq!(
+        tables,
+        vec![
+            ("users", &SqlValue::Text("Alice".to_string())),
+            ("roles", &SqlValue::Text("admin".to_string())),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_render_sql_entries_and_hooks() {
+    let seeder = SeederFormat::Ron
+        .parse(
+            r#"
+#![vars(start: 100)]
+#![before("DELETE FROM users")]
+#![after(
+    "SELECT setval('users_id_seq', {start})",
+    "ANALYZE users",
+)]
+
+{
+    users: [(id: 1, name: "Alice")],
+    #[sql] _: "UPDATE users SET name = 'Bob' WHERE id = 1",
+    roles: [(name: "admin")],
+}
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+    let statements = render_statements(seeder, "mock://").await.unwrap();
+
+    let sql = |text: &str| Statement::Sql(text.to_string());
+    assert_eq!(statements.len(), 6);
+    assert_eq!(statements[0], sql("DELETE FROM users"));
+    assert!(
+        matches!(&statements[1], Statement::Insert { table, .. } if table == "users")
+    );
+    assert_eq!(
+        statements[2],
+        sql("UPDATE users SET name = 'Bob' WHERE id = 1")
+    );
+    assert!(
+        matches!(&statements[3], Statement::Insert { table, .. } if table == "roles")
+    );
+    assert_eq!(statements[4], sql("SELECT setval('users_id_seq', 100)"));
+    assert_eq!(statements[5], sql("ANALYZE users"));
+}
+
+#[test]
+fn test_sql_entry_errors() {
+    let parse = |content: &str| SeederFormat::Ron.parse(content.to_string());
+
+    let err = parse("{ #[sql] _: (name: \"admin\") }").unwrap_err();
+    assert!(err.contains("Expect string as value of #[sql]"), "{err}");
+
+    let err = parse("#![after(42)] { users: [] }").unwrap_err();
+    assert!(
+        err.contains("Expect SQL strings in #![after(...)]"),
+        "{err}"
+    );
+
+    let seeder = parse(
+        "#![before()] { #[sql] _: \"SELECT 1\", #[sql] _: \"SELECT 2\" }",
+    )
+    .unwrap();
+    assert_eq!(seeder.entries.len(), 2);
+}
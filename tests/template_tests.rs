@@ -1,4 +1,8 @@
-use grow_rs::commands::run::{entry::Entry, template};
+mod common;
+
+use common::render_tables;
+use grow_rs::commands::run::entry::{Entry, EntryOptions, Seeder};
+use grow_rs::commands::run::template;
 use grow_rs::SqlValue;
 use std::collections::BTreeMap;
 
@@ -31,10 +35,11 @@ async fn test_render_tables_static_entry() {
     let entry = Entry::Static {
         table_name: "users".to_string(),
         values,
+        options: EntryOptions::default(),
     };
 
     let entries = vec![entry];
-    let result = template::render_tables(entries, MOCK_DB).await;
+    let result = render_tables(entries, MOCK_DB).await;
 
     assert!(result.is_ok());
     let tables = result.unwrap();
@@ -69,10 +74,11 @@ async fn test_render_tables_repeat_entry() {
         count: 3,
         table_name: "products".to_string(),
         fields,
+        options: EntryOptions::default(),
     };
 
     let entries = vec![entry];
-    let result = template::render_tables(entries, MOCK_DB).await;
+    let result = render_tables(entries, MOCK_DB).await;
 
     assert!(result.is_ok());
     let tables = result.unwrap();
@@ -104,6 +110,7 @@ async fn test_render_tables_multiple_tables() {
     let users_entry = Entry::Static {
         table_name: "users".to_string(),
         values: vec![users_fields],
+        options: EntryOptions::default(),
     };
 
     let mut products_fields = BTreeMap::new();
@@ -114,10 +121,11 @@ async fn test_render_tables_multiple_tables() {
         count: 2,
         table_name: "products".to_string(),
         fields: products_fields,
+        options: EntryOptions::default(),
     };
 
     let entries = vec![users_entry, products_entry];
-    let result = template::render_tables(entries, MOCK_DB).await;
+    let result = render_tables(entries, MOCK_DB).await;
 
     assert!(result.is_ok());
     let tables = result.unwrap();
@@ -132,11 +140,12 @@ async fn test_render_tables_multiple_tables() {
 
 #[tokio::test]
 async fn test_render_tables_empty_entries() {
-    let result = template::render_tables(vec![], MOCK_DB).await;
+    let result = render_tables(vec![], MOCK_DB).await;
     assert!(result.is_ok());
     assert_eq!(result.unwrap().len(), 0);
 }
 
+#[allow(clippy::approx_constant)]
 #[tokio::test]
 async fn test_render_tables_mixed_sql_value_types() {
     let mut fields = BTreeMap::new();
@@ -152,9 +161,10 @@ async fn test_render_tables_mixed_sql_value_types() {
     let entry = Entry::Static {
         table_name: "mixed_types".to_string(),
         values: vec![fields],
+        options: EntryOptions::default(),
     };
 
-    let result = template::render_tables(vec![entry], MOCK_DB).await;
+    let result = render_tables(vec![entry], MOCK_DB).await;
     assert!(result.is_ok());
     let tables = result.unwrap();
 
@@ -178,17 +188,321 @@ async fn test_render_tables_mixed_sql_value_types() {
         .any(|(k, v)| k == "null_col" && matches!(v, SqlValue::Null)));
 }
 
+#[cfg(feature = "fake")]
+#[test]
+fn test_render_signed_and_duration_literals() {
+    let templating = template::start();
+
+    let value = template::render(&templating, "{rand_int(-3, -3)}").unwrap();
+    assert_eq!(value, "-3");
+
+    let value = template::render(&templating, "at {datetime_past(1d)}");
+    assert!(value.unwrap().starts_with("at 20"));
+
+    // Literals outside of an expression are kept as they are
+    let value = template::render(&templating, "(-7d) {rand_int(1, 1)}");
+    assert_eq!(value.unwrap(), "(-7d) 1");
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn test_render_fake_arguments() {
+    let templating = template::start();
+
+    let words = template::render(&templating, "{fake(WORDS, 2, 2)}").unwrap();
+    assert_eq!(words.split(' ').count(), 2);
+
+    let date = template::render(
+        &templating,
+        r#"{fake(DATE_TIME_BETWEEN, "2024-03-01 00:00:00", "2024-03-01 06:00:00")}"#,
+    )
+    .unwrap();
+    assert!(date.starts_with("2024-03-01"), "{date}");
+}
+
+#[test]
+fn test_typed_value() {
+    let value = template::typed_value("{rand_int(1, 10)}", "7".to_string());
+    assert_eq!(value, SqlValue::Integer(7));
+
+    let value =
+        template::typed_value("{rand_float(0.0, 1.0, 2)}", "0.5".to_string());
+    assert_eq!(value, SqlValue::Float(0.5));
+
+    let value = template::typed_value(
+        r#"{date_between("2024-01-01", "2024-12-31")}"#,
+        "2024-06-01".to_string(),
+    );
+    assert_eq!(value, SqlValue::Date("2024-06-01".to_string()));
+
+    let value = template::typed_value(
+        "{ datetime_past(30d) }",
+        "2024-06-01 10:00:00".to_string(),
+    );
+    assert_eq!(
+        value,
+        SqlValue::Timestamp("2024-06-01 10:00:00".to_string())
+    );
+
+    // Calls mixed with text stay as text
+    let value = template::typed_value("#{rand_int(1, 10)}", "#7".to_string());
+    assert_eq!(value, SqlValue::Text("#7".to_string()));
+
+    let value = template::typed_value(
+        "{rand_int(1, 10)}{rand_int(1, 10)}",
+        "77".to_string(),
+    );
+    assert_eq!(value, SqlValue::Text("77".to_string()));
+}
+
+#[cfg(feature = "fake")]
+#[tokio::test]
+async fn test_render_tables_typed_generators() {
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "stock".to_string(),
+        SqlValue::Text("{rand_int(1, 1000)}".to_string()),
+    );
+    fields.insert(
+        "price".to_string(),
+        SqlValue::Text("{rand_float(0.0, 99.99, 2)}".to_string()),
+    );
+    fields.insert(
+        "released".to_string(),
+        SqlValue::Text(
+            r#"{date_between("2023-01-01", "2024-12-31")}"#.to_string(),
+        ),
+    );
+    fields.insert(
+        "sku".to_string(),
+        SqlValue::Text("SKU-{rand_int(1, 9)}".to_string()),
+    );
+
+    let entry = Entry::Repeat {
+        count: 5,
+        table_name: "products".to_string(),
+        fields,
+        options: EntryOptions::default(),
+    };
+
+    let tables = render_tables(vec![entry], MOCK_DB).await.unwrap();
+
+    for row in tables.get("products").unwrap() {
+        assert!(matches!(row[0].1, SqlValue::Float(_)));
+        assert!(matches!(row[1].1, SqlValue::Date(_)));
+        assert!(
+            matches!(row[2].1, SqlValue::Text(ref s) if s.starts_with("SKU-"))
+        );
+        assert!(matches!(row[3].1, SqlValue::Integer(1..=1000)));
+    }
+}
+
+#[tokio::test]
+async fn test_render_tables_uuid_values() {
+    let mut fields = BTreeMap::new();
+    fields.insert("id".to_string(), SqlValue::Text("{uuid()}".to_string()));
+    fields.insert(
+        "slug".to_string(),
+        SqlValue::Text("user-{nanoid(8)}".to_string()),
+    );
+
+    let entry = Entry::Repeat {
+        count: 2,
+        table_name: "users".to_string(),
+        fields,
+        options: EntryOptions::default(),
+    };
+
+    let tables = render_tables(vec![entry], MOCK_DB).await.unwrap();
+    let users = tables.get("users").unwrap();
+
+    assert!(matches!(users[0][0].1, SqlValue::Uuid(_)));
+    assert!(matches!(users[0][1].1, SqlValue::Text(ref s) if s.len() == 13));
+    assert_ne!(users[0][0].1, users[1][0].1);
+}
+
 #[test]
-fn test_rendered_table_type_alias() {
-    let mut table: template::RenderedTable = Vec::new();
-    let row = vec![
-        ("id".to_string(), SqlValue::Integer(1)),
-        ("name".to_string(), SqlValue::Text("Test".to_string())),
+fn test_render_name_arguments() {
+    std::env::set_var("GROW_TEST_DOMAIN", "example.com");
+    let templating = template::start();
+
+    assert_eq!(
+        template::render(&templating, "admin@{env(GROW_TEST_DOMAIN)}").unwrap(),
+        "admin@example.com"
+    );
+    assert_eq!(
+        template::render(&templating, r#"{env("GROW_TEST_DOMAIN")}"#).unwrap(),
+        "example.com"
+    );
+    assert_eq!(
+        template::render(&templating, r#"{env(GROW_TEST_UNSET, "none")}"#)
+            .unwrap(),
+        "none"
+    );
+    assert!(template::render(&templating, "{env(GROW_TEST_UNSET)}").is_err());
+
+    assert_eq!(
+        template::render(&templating, r#"{uuid_v5(dns, "example.com")}"#)
+            .unwrap(),
+        "cfbff0d1-9375-5685-968c-48ce8b15ae17"
+    );
+}
+
+#[tokio::test]
+async fn test_render_tables_with_vars() {
+    std::env::set_var("GROW_TEST_MAIL_DOMAIN", "example.org");
+
+    let seeder = Seeder::parse(
+        r#"// Shared values
+#![vars(
+    domain: "{env(GROW_TEST_MAIL_DOMAIN)}",
+    admin_email: "ops@{domain}",
+    quota: 10,
+)]
+
+{
+    #[repeat = 2]
+    users: (email: "{admin_email}", quota: "{quota}", name: "user_{i}"),
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(seeder.vars.len(), 3);
+    assert_eq!(seeder.vars[2], ("quota".to_string(), "10".to_string()));
+
+    let tables = render_tables(seeder, MOCK_DB).await.unwrap();
+    let users = tables.get("users").unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1][0].1, SqlValue::Text("ops@example.org".to_string()));
+    assert_eq!(users[1][1].1, SqlValue::Text("user_1".to_string()));
+    assert_eq!(users[1][2].1, SqlValue::Text("10".to_string()));
+}
+
+#[test]
+fn test_parse_vars_errors() {
+    let invalid = [
+        r#"#![vars(i: 1)] { users: [] }"#,
+        r#"#![vars = 1] { users: [] }"#,
+        r#"#![vars(name: [1, 2])] { users: [] }"#,
     ];
-    table.push(row);
 
-    assert_eq!(table.len(), 1);
-    assert_eq!(table[0].len(), 2);
-    assert_eq!(table[0][0].0, "id");
-    assert_eq!(table[0][0].1, SqlValue::Integer(1));
+    for content in invalid {
+        assert!(Seeder::parse(content.to_string()).is_err(), "{content}");
+    }
+
+    let seeder = Seeder::parse("#![vars()]\n{ users: [] }".to_string());
+    assert!(seeder.unwrap().vars.is_empty());
+}
+
+#[test]
+fn test_parse_escapes_only_in_template_calls() {
+    let seeder = Seeder::parse(
+        r#"{
+    files: [(
+        path: "C:\\seeds\\{name}",
+        quote: "say \"hi\"",
+        day: "{date_between(\"2024-01-01\", \"2024-01-01\")}",
+    )],
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let Entry::Static { values, .. } = &seeder.entries[0] else {
+        panic!("Expected static entry");
+    };
+    let row = &values[0];
+    assert_eq!(
+        row["path"],
+        SqlValue::Text(r"C:\\seeds\\{name}".to_string())
+    );
+    assert_eq!(row["quote"], SqlValue::Text(r#"say \"hi\""#.to_string()));
+    assert_eq!(
+        row["day"],
+        SqlValue::Text(r#"{date_between("2024-01-01", "2024-01-01")}"#.into())
+    );
+}
+
+// Unique values are tracked for the whole run, so each test uses its own table
+#[tokio::test]
+async fn test_render_tables_unique_columns() {
+    let seeder = Seeder::parse(
+        r#"{
+    #[repeat = 3]
+    #[unique = ["code", "slot"]]
+    unique_codes: (
+        code: "{choice(\"a\", \"b\", \"c\")}",
+        slot: "{choice(\"x\", \"y\", \"z\")}",
+        free: "{choice(\"a\", \"b\")}",
+    ),
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let tables = render_tables(seeder, MOCK_DB).await.unwrap();
+    let rows = &tables["unique_codes"];
+
+    let mut codes: Vec<_> =
+        rows.iter().map(|row| row[0].1.to_string()).collect();
+    codes.sort();
+    assert_eq!(codes, ["a", "b", "c"]);
+
+    let mut slots: Vec<_> =
+        rows.iter().map(|row| row[2].1.to_string()).collect();
+    slots.sort();
+    assert_eq!(slots, ["x", "y", "z"]);
+}
+
+#[tokio::test]
+async fn test_render_tables_unique_exhausted() {
+    let seeder = Seeder::parse(
+        r#"{
+    #[repeat = 3]
+    #[unique = "code"]
+    exhausted_codes: (code: "{choice(\"a\", \"b\")}"),
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let err = render_tables(seeder, MOCK_DB).await.unwrap_err();
+    assert!(err.contains("exhausted_codes.code"), "{err}");
+    assert!(err.contains("choice"), "{err}");
+}
+
+#[tokio::test]
+async fn test_render_tables_unique_unknown_column() {
+    let seeder = Seeder::parse(
+        r#"{ #[unique = ["email"]] unknown_columns: [(name: "Ana")] }"#
+            .to_string(),
+    )
+    .unwrap();
+
+    let err = render_tables(seeder, MOCK_DB).await.unwrap_err();
+    assert!(err.contains("unknown_columns.email"), "{err}");
+
+    let invalid = r#"{ #[unique = [1]] unknown_columns: [(name: "Ana")] }"#;
+    assert!(Seeder::parse(invalid.to_string()).is_err());
+}
+
+#[test]
+fn test_seeder_kept_sequences() {
+    let seeder = Seeder::parse(
+        r#"{
+    #[reset_sequence = false] legacy_users: [(id: 1, name: "Ana")],
+    #[reset_sequence = true] roles: [(id: 1, name: "admin")],
+    #[schema = "auth"] #[reset_sequence = false] sessions: [(id: 1)],
+    posts: [(id: 1)],
+}"#
+        .to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        seeder.kept_sequences().into_iter().collect::<Vec<_>>(),
+        ["auth.sessions", "legacy_users"]
+    );
 }
@@ -1,4 +1,7 @@
-use grow_rs::utils::map_io_error;
+use grow_rs::utils::get_seeders::unprefixed_seeders;
+use grow_rs::utils::{
+    map_io_error, seeder_names, seeder_timestamp, seeder_tree,
+};
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
 
@@ -58,3 +61,135 @@ fn test_map_io_error_with_different_error_kinds() {
         assert!(result.contains(message));
     }
 }
+
+#[test]
+fn test_seeder_names_nested() {
+    let root = std::env::temp_dir().join("grow_seeder_names");
+    let _ = std::fs::remove_dir_all(&root);
+
+    for file in [
+        "1_admins.ron",
+        "catalogs/2_countries.yaml",
+        "catalogs/2_countries.ron",
+        "demo/users.sql",
+        "demo/shop/orders.json",
+        "demo/notes.txt",
+        "_shared/roles.ron",
+        "demo/_partial.ron",
+    ] {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "{}").unwrap();
+    }
+
+    // Data files of `#[json]` entries are not seeders
+    std::fs::create_dir_all(root.join("fixtures")).unwrap();
+    for (file, content) in [
+        ("fixtures/roles.json", r#"[{"name": "admin"}]"#),
+        ("fixtures/roles.yaml", "- name: admin"),
+        ("3_teams.json", "\n  {\"teams\": []}"),
+    ] {
+        std::fs::write(root.join(file), content).unwrap();
+    }
+
+    assert_eq!(
+        seeder_names(&root).unwrap(),
+        vec![
+            "1_admins",
+            "catalogs/2_countries",
+            "3_teams",
+            "demo/shop/orders",
+            "demo/users",
+        ]
+    );
+}
+
+#[test]
+fn test_seeder_timestamp() {
+    assert_eq!(seeder_timestamp("1700000000_users"), Some(1700000000));
+    assert_eq!(seeder_timestamp("demo/1700000000_users"), Some(1700000000));
+    assert_eq!(seeder_timestamp("1700000000_demo/users"), None);
+    assert_eq!(seeder_timestamp("users"), None);
+    assert_eq!(seeder_timestamp("v2_users"), None);
+}
+
+#[test]
+fn test_seeder_names_order() {
+    let root = std::env::temp_dir().join("grow_seeder_order");
+    let _ = std::fs::remove_dir_all(&root);
+
+    for file in [
+        "users.ron",
+        "900_roles.ron",
+        "1000_accounts.ron",
+        "demo/950_demo.ron",
+        "demo/extra.ron",
+        "100_b.ron",
+        "100_a.ron",
+    ] {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "{}").unwrap();
+    }
+
+    // Numeric order of the prefix, the name breaks ties and seeders without
+    // a prefix go last
+    assert_eq!(
+        seeder_names(&root).unwrap(),
+        vec![
+            "100_a",
+            "100_b",
+            "900_roles",
+            "demo/950_demo",
+            "1000_accounts",
+            "demo/extra",
+            "users",
+        ]
+    );
+}
+
+#[test]
+fn test_unprefixed_seeders() {
+    let names = ["100_a", "demo/950_demo", "demo/extra", "users", "v2_users"]
+        .map(String::from);
+
+    assert_eq!(
+        unprefixed_seeders(&names),
+        vec!["demo/extra", "users", "v2_users"]
+    );
+}
+
+#[test]
+fn test_seeder_tree() {
+    let names = [
+        "1_admins",
+        "catalogs/countries",
+        "demo/shop/orders",
+        "demo/users",
+        "zones",
+        "demo/extra",
+    ]
+    .map(str::to_owned);
+
+    let lines = seeder_tree(&names)
+        .into_iter()
+        .map(|(depth, line, seeder)| (depth, line, seeder.cloned()))
+        .collect::<Vec<_>>();
+
+    let seeder = |name: &str| Some(name.to_owned());
+    assert_eq!(
+        lines,
+        vec![
+            (0, "1_admins".to_owned(), seeder("1_admins")),
+            (0, "catalogs/".to_owned(), None),
+            (1, "countries".to_owned(), seeder("catalogs/countries")),
+            (0, "demo/".to_owned(), None),
+            (1, "shop/".to_owned(), None),
+            (2, "orders".to_owned(), seeder("demo/shop/orders")),
+            (1, "users".to_owned(), seeder("demo/users")),
+            (0, "zones".to_owned(), seeder("zones")),
+            (0, "demo/".to_owned(), None),
+            (1, "extra".to_owned(), seeder("demo/extra")),
+        ]
+    );
+}
@@ -0,0 +1,52 @@
+use grow_rs::commands::run::template::{render, start};
+use grow_rs::commands::run::variables::{
+    env, parse_assignment, set_overrides, validate_name,
+};
+
+#[test]
+fn test_parse_assignment() {
+    assert_eq!(
+        parse_assignment("admin_email=ops@example.com").unwrap(),
+        ("admin_email".to_string(), "ops@example.com".to_string())
+    );
+    assert_eq!(
+        parse_assignment("query=a=b").unwrap(),
+        ("query".to_string(), "a=b".to_string())
+    );
+    assert!(parse_assignment("missing").is_err());
+    assert!(parse_assignment("1st=value").is_err());
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(validate_name("admin_email").is_ok());
+    assert!(validate_name("_private").is_ok());
+    assert!(validate_name("").is_err());
+    assert!(validate_name("admin-email").is_err());
+    assert!(validate_name("i").is_err());
+}
+
+#[test]
+fn test_env() {
+    std::env::set_var("GROW_TEST_ENV", "value");
+
+    assert_eq!(env(&["GROW_TEST_ENV".to_string()]).unwrap(), "value");
+    assert_eq!(
+        env(&["GROW_TEST_MISSING".to_string(), "default".to_string()]).unwrap(),
+        "default"
+    );
+    assert!(env(&["GROW_TEST_MISSING".to_string()]).is_err());
+    assert!(env(&[]).is_err());
+}
+
+// Overrides are global, so they are only set in this test
+#[test]
+fn test_overrides_are_template_variables() {
+    set_overrides(vec![("region".to_string(), "eu".to_string())]);
+
+    let templating = start();
+    assert_eq!(render(&templating, "{region}-1").unwrap(), "eu-1");
+
+    set_overrides(vec![]);
+    assert!(render(&start(), "{region}").is_err());
+}